@@ -12,25 +12,37 @@ use crate::{
       cycle_focus, disable_binding_mode, enable_binding_mode,
       reload_config, shell_exec,
     },
+    platform::Platform,
     Direction, LengthValue, RectDelta,
   },
   containers::{
-    commands::{focus_in_direction, toggle_tiling_direction},
+    commands::{
+      balance_siblings, flatten_container, focus_in_direction,
+      rotate_layout, toggle_tiling_direction,
+    },
     traits::CommonGetters,
     Container,
   },
   user_config::{FloatingStateConfig, FullscreenStateConfig, UserConfig},
   windows::{
     commands::{
-      ignore_window, move_window_in_direction, move_window_to_workspace,
-      resize_window, set_window_size, update_window_state,
+      center_window, focus_previous_window, group_windows, ignore_window,
+      jump_to_mark, mark_window, move_window_by, move_window_in_direction,
+      move_window_to_monitor, move_window_to_workspace, rename_window,
+      resize_window, select_window, set_scratchpad, set_window_size,
+      swap_window_in_direction, toggle_scratchpad,
+      toggle_window_select_mode, unignore_window, update_window_state,
     },
     traits::WindowGetters,
     WindowState,
   },
   wm_state::WmState,
   workspaces::{
-    commands::{focus_workspace, move_workspace_in_direction},
+    commands::{
+      focus_workspace, lock_workspace, move_workspace_in_direction,
+      rename_workspace, switch_to_previous_workspace, toggle_monocle,
+      unlock_workspace,
+    },
     WorkspaceTarget,
   },
 };
@@ -50,6 +62,19 @@ pub enum AppCommand {
 
     #[clap(flatten)]
     verbosity: Verbosity,
+
+    /// Outputs emitted WM events to stderr.
+    #[clap(long, action)]
+    debug_events: bool,
+
+    /// Validates the user config and exits without starting the WM.
+    #[clap(long, action)]
+    dry_run: bool,
+
+    /// Gracefully exits an already running instance of the WM before
+    /// starting.
+    #[clap(long, action)]
+    replace: bool,
   },
 
   /// Retrieves and outputs a specific part of the window manager's state.
@@ -105,6 +130,9 @@ impl AppCommand {
           verbose: false,
           quiet: false,
         },
+        debug_events: false,
+        dry_run: false,
+        replace: false,
       },
       false => AppCommand::parse_from(args),
     }
@@ -141,6 +169,9 @@ pub enum QueryCommand {
   AppMetadata,
   /// Outputs the active binding modes.
   BindingModes,
+  /// Outputs the most recently emitted WM events (up to 100), with the
+  /// oldest event first.
+  EventLog,
   /// Outputs the focused container (either a window or an empty
   /// workspace).
   Focused,
@@ -149,7 +180,11 @@ pub enum QueryCommand {
   /// Outputs all monitors.
   Monitors,
   /// Outputs all windows.
-  Windows,
+  Windows {
+    /// Whether to include the PNG-encoded icon of each window.
+    #[clap(long, action)]
+    include_icons: bool,
+  },
   /// Outputs all active workspaces.
   Workspaces,
 }
@@ -177,15 +212,56 @@ pub enum SubscribableEvent {
 #[derive(Clone, Debug, Parser, PartialEq, Serialize)]
 pub enum InvokeCommand {
   AdjustBorders(InvokeAdjustBordersCommand),
+  BalanceSiblings,
+  Center,
   Close,
+  /// Flattens the split container of the subject container (or the
+  /// subject container itself if it's a split container), moving its
+  /// children into the parent container.
+  Flatten,
   Focus(InvokeFocusCommand),
+  FocusPreviousWindow,
+  /// Groups the given tiling windows into a new split container.
+  GroupWindows {
+    #[clap(long, value_delimiter = ',', num_args = 1..)]
+    window_ids: Vec<Uuid>,
+  },
   Ignore,
+  JumpToMark {
+    #[clap(long)]
+    label: char,
+  },
+  LockWorkspace,
+  Mark {
+    #[clap(long)]
+    label: char,
+  },
   Move(InvokeMoveCommand),
+  MoveBy {
+    #[clap(long)]
+    direction: Direction,
+
+    #[clap(long, allow_hyphen_values = true)]
+    distance: LengthValue,
+  },
   MoveWorkspace {
     #[clap(long)]
     direction: Direction,
   },
+  RenameWindow {
+    #[clap(long)]
+    title: String,
+  },
+  RenameWorkspace {
+    #[clap(long)]
+    display_name: String,
+  },
   Resize(InvokeResizeCommand),
+  RotateLayout,
+  SelectWindow {
+    #[clap(long)]
+    label: char,
+  },
   SetFloating {
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
     shown_on_top: Option<bool>,
@@ -199,8 +275,20 @@ pub enum InvokeCommand {
 
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
     maximized: Option<bool>,
+
+    #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
+    remove_title_bar: Option<bool>,
   },
   SetMinimized,
+  SetOpacity {
+    /// Opacity of the window as a percentage (0-100).
+    #[clap(long, value_parser = clap::value_parser!(u8).range(0..=100))]
+    opacity: u8,
+  },
+  SetScratchpad {
+    #[clap(long)]
+    name: String,
+  },
   SetTiling,
   ShellExec {
     #[clap(required = true, trailing_var_arg = true)]
@@ -208,6 +296,11 @@ pub enum InvokeCommand {
   },
   // Reuse `InvokeResizeCommand` struct.
   Size(InvokeResizeCommand),
+  Swap {
+    #[clap(long)]
+    direction: Direction,
+  },
+  SwitchToPreviousWorkspace,
   ToggleFloating {
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
     shown_on_top: Option<bool>,
@@ -221,10 +314,21 @@ pub enum InvokeCommand {
 
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
     maximized: Option<bool>,
+
+    #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
+    remove_title_bar: Option<bool>,
   },
   ToggleMinimized,
+  ToggleMonocle,
+  ToggleScratchpad {
+    #[clap(long)]
+    name: String,
+  },
   ToggleTiling,
   ToggleTilingDirection,
+  Unignore,
+  UnlockWorkspace,
+  WindowSelectMode,
   WmCycleFocus {
     #[clap(long, default_value_t = false)]
     omit_fullscreen: bool,
@@ -256,6 +360,20 @@ impl InvokeCommand {
       bail!("Cannot run command because subject container is detached.");
     }
 
+    if let Some(workspace) = subject_container.workspace() {
+      if workspace.is_locked()
+        && self.is_layout_change(&subject_container, config)
+      {
+        warn!(
+          "Skipping command on locked workspace '{}': {:?}.",
+          workspace.config().name,
+          self
+        );
+
+        return Ok(());
+      }
+    }
+
     match self {
       InvokeCommand::AdjustBorders(args) => {
         match subject_container.as_window_container() {
@@ -276,6 +394,18 @@ impl InvokeCommand {
           _ => Ok(()),
         }
       }
+      InvokeCommand::BalanceSiblings => {
+        match subject_container.as_tiling_container() {
+          Ok(container) => balance_siblings(container, state),
+          _ => Ok(()),
+        }
+      }
+      InvokeCommand::Center => {
+        match subject_container.as_window_container() {
+          Ok(window) => center_window(window, state, config),
+          _ => Ok(()),
+        }
+      }
       InvokeCommand::Close => {
         match subject_container.as_window_container() {
           Ok(window) => {
@@ -289,11 +419,22 @@ impl InvokeCommand {
           _ => Ok(()),
         }
       }
+      InvokeCommand::Flatten => {
+        flatten_container(subject_container, state)
+      }
       InvokeCommand::Focus(args) => {
         if let Some(direction) = &args.direction {
           focus_in_direction(subject_container, direction, state)?;
         }
 
+        if let Some(direction) = &args.monitor {
+          focus_workspace(
+            WorkspaceTarget::Direction(direction.clone()),
+            state,
+            config,
+          )?;
+        }
+
         if let Some(name) = &args.workspace {
           focus_workspace(
             WorkspaceTarget::Name(name.to_string()),
@@ -316,12 +457,43 @@ impl InvokeCommand {
 
         Ok(())
       }
+      InvokeCommand::FocusPreviousWindow => {
+        focus_previous_window(state, config)
+      }
+      InvokeCommand::GroupWindows { window_ids } => {
+        let windows = window_ids
+          .iter()
+          .map(|id| {
+            state
+              .container_by_id(*id)
+              .and_then(|container| container.as_tiling_window().cloned())
+              .with_context(|| format!("No tiling window with ID {}.", id))
+          })
+          .try_collect()?;
+
+        group_windows(windows, state, config)
+      }
       InvokeCommand::Ignore => {
         match subject_container.as_window_container() {
           Ok(window) => ignore_window(window, state),
           _ => Ok(()),
         }
       }
+      InvokeCommand::JumpToMark { label } => {
+        jump_to_mark(*label, state, config)
+      }
+      InvokeCommand::LockWorkspace => {
+        let workspace =
+          subject_container.workspace().context("No workspace.")?;
+
+        lock_workspace(workspace, state)
+      }
+      InvokeCommand::Mark { label } => {
+        match subject_container.as_window_container() {
+          Ok(window) => mark_window(window, *label, state),
+          _ => Ok(()),
+        }
+      }
       InvokeCommand::Move(args) => {
         match subject_container.as_window_container() {
           Ok(window) => {
@@ -334,6 +506,15 @@ impl InvokeCommand {
               )?;
             };
 
+            if let Some(direction) = &args.monitor {
+              move_window_to_monitor(
+                window.clone(),
+                direction,
+                state,
+                config,
+              )?;
+            };
+
             if let Some(name) = &args.workspace {
               move_window_to_workspace(
                 window.clone(),
@@ -375,6 +556,13 @@ impl InvokeCommand {
           _ => Ok(()),
         }
       }
+      InvokeCommand::MoveBy {
+        direction,
+        distance,
+      } => match subject_container.as_window_container() {
+        Ok(window) => move_window_by(window, direction, distance, state),
+        _ => Ok(()),
+      },
       InvokeCommand::MoveWorkspace { direction } => {
         let workspace =
           subject_container.workspace().context("No workspace.")?;
@@ -386,6 +574,18 @@ impl InvokeCommand {
           config,
         )
       }
+      InvokeCommand::RenameWindow { title } => {
+        match subject_container.as_window_container() {
+          Ok(window) => rename_window(window, title),
+          _ => Ok(()),
+        }
+      }
+      InvokeCommand::RenameWorkspace { display_name } => {
+        let workspace =
+          subject_container.workspace().context("No workspace.")?;
+
+        rename_workspace(workspace, display_name, state, config)
+      }
       InvokeCommand::Resize(args) => {
         match subject_container.as_window_container() {
           Ok(window) => resize_window(
@@ -393,10 +593,17 @@ impl InvokeCommand {
             args.width.clone(),
             args.height.clone(),
             state,
+            config,
           ),
           _ => Ok(()),
         }
       }
+      InvokeCommand::RotateLayout => {
+        rotate_layout(subject_container, state)
+      }
+      InvokeCommand::SelectWindow { label } => {
+        select_window(*label, state)
+      }
       InvokeCommand::SetFloating {
         centered,
         shown_on_top,
@@ -423,6 +630,7 @@ impl InvokeCommand {
       InvokeCommand::SetFullscreen {
         maximized,
         shown_on_top,
+        remove_title_bar,
       } => match subject_container.as_window_container() {
         Ok(window) => {
           let fullscreen_defaults =
@@ -435,6 +643,8 @@ impl InvokeCommand {
                 .unwrap_or(fullscreen_defaults.maximized),
               shown_on_top: shown_on_top
                 .unwrap_or(fullscreen_defaults.shown_on_top),
+              remove_title_bar: remove_title_bar
+                .unwrap_or(fullscreen_defaults.remove_title_bar),
             }),
             state,
             config,
@@ -459,6 +669,23 @@ impl InvokeCommand {
           _ => Ok(()),
         }
       }
+      InvokeCommand::SetOpacity { opacity } => {
+        match subject_container.as_window_container() {
+          Ok(window) => {
+            window.set_opacity(Some(*opacity));
+            state.pending_sync.reset_window_effects = true;
+
+            Ok(())
+          }
+          _ => Ok(()),
+        }
+      }
+      InvokeCommand::SetScratchpad { name } => {
+        match subject_container.as_window_container() {
+          Ok(window) => set_scratchpad(window, name, state),
+          _ => Ok(()),
+        }
+      }
       InvokeCommand::SetTiling => {
         match subject_container.as_window_container() {
           Ok(window) => {
@@ -484,10 +711,22 @@ impl InvokeCommand {
             args.width.clone(),
             args.height.clone(),
             state,
+            config,
           ),
           _ => Ok(()),
         }
       }
+      InvokeCommand::Swap { direction } => {
+        match subject_container.as_tiling_window() {
+          Some(window) => {
+            swap_window_in_direction(window.clone(), direction, state)
+          }
+          _ => Ok(()),
+        }
+      }
+      InvokeCommand::SwitchToPreviousWorkspace => {
+        switch_to_previous_workspace(state, config)
+      }
       InvokeCommand::ToggleFloating {
         centered,
         shown_on_top,
@@ -516,6 +755,7 @@ impl InvokeCommand {
       InvokeCommand::ToggleFullscreen {
         maximized,
         shown_on_top,
+        remove_title_bar,
       } => match subject_container.as_window_container() {
         Ok(window) => {
           let fullscreen_defaults =
@@ -527,6 +767,8 @@ impl InvokeCommand {
                 .unwrap_or(fullscreen_defaults.maximized),
               shown_on_top: shown_on_top
                 .unwrap_or(fullscreen_defaults.shown_on_top),
+              remove_title_bar: remove_title_bar
+                .unwrap_or(fullscreen_defaults.remove_title_bar),
             });
 
           update_window_state(
@@ -555,6 +797,15 @@ impl InvokeCommand {
           _ => Ok(()),
         }
       }
+      InvokeCommand::ToggleMonocle => {
+        let workspace =
+          subject_container.workspace().context("No workspace.")?;
+
+        toggle_monocle(workspace, state)
+      }
+      InvokeCommand::ToggleScratchpad { name } => {
+        toggle_scratchpad(name, state, config)
+      }
       InvokeCommand::ToggleTiling => {
         match subject_container.as_window_container() {
           Ok(window) => {
@@ -573,6 +824,18 @@ impl InvokeCommand {
       InvokeCommand::ToggleTilingDirection => {
         toggle_tiling_direction(subject_container, state, config)
       }
+      InvokeCommand::Unignore => {
+        // Ignored windows aren't part of the container tree, so the
+        // foreground window is used instead of the subject container.
+        unignore_window(Platform::foreground_window(), state, config)
+      }
+      InvokeCommand::UnlockWorkspace => {
+        let workspace =
+          subject_container.workspace().context("No workspace.")?;
+
+        unlock_workspace(workspace, state)
+      }
+      InvokeCommand::WindowSelectMode => toggle_window_select_mode(state),
       InvokeCommand::WmCycleFocus {
         omit_fullscreen,
         omit_minimized,
@@ -600,6 +863,63 @@ impl InvokeCommand {
       InvokeCommand::WmReloadConfig => reload_config(state, config),
     }
   }
+
+  /// Whether the command adds, removes, or moves tiling windows when run
+  /// on the given subject container. These commands are skipped on locked
+  /// workspaces.
+  fn is_layout_change(
+    &self,
+    subject_container: &Container,
+    config: &UserConfig,
+  ) -> bool {
+    // Non-tiling windows can still be changed freely, as long as they
+    // don't become tiling.
+    if let Some(window) = subject_container.as_non_tiling_window() {
+      let state_defaults = &config.value.window_behavior.state_defaults;
+
+      let toggles_to_tiling = |target_state: WindowState| {
+        window.toggled_state(target_state, config) == WindowState::Tiling
+      };
+
+      return match self {
+        InvokeCommand::SetTiling | InvokeCommand::ToggleTiling => true,
+        InvokeCommand::ToggleFloating { .. } => toggles_to_tiling(
+          WindowState::Floating(state_defaults.floating.clone()),
+        ),
+        InvokeCommand::ToggleFullscreen { .. } => toggles_to_tiling(
+          WindowState::Fullscreen(state_defaults.fullscreen.clone()),
+        ),
+        InvokeCommand::ToggleMinimized => {
+          toggles_to_tiling(WindowState::Minimized)
+        }
+        _ => false,
+      };
+    }
+
+    matches!(
+      self,
+      InvokeCommand::BalanceSiblings
+        | InvokeCommand::Center
+        | InvokeCommand::Flatten
+        | InvokeCommand::GroupWindows { .. }
+        | InvokeCommand::Ignore
+        | InvokeCommand::Move(_)
+        | InvokeCommand::Resize(_)
+        | InvokeCommand::RotateLayout
+        | InvokeCommand::SetFloating { .. }
+        | InvokeCommand::SetFullscreen { .. }
+        | InvokeCommand::SetMinimized
+        | InvokeCommand::SetScratchpad { .. }
+        | InvokeCommand::SetTiling
+        | InvokeCommand::Size(_)
+        | InvokeCommand::Swap { .. }
+        | InvokeCommand::ToggleFloating { .. }
+        | InvokeCommand::ToggleFullscreen { .. }
+        | InvokeCommand::ToggleMinimized
+        | InvokeCommand::ToggleTiling
+        | InvokeCommand::ToggleTilingDirection
+    )
+  }
 }
 
 impl<'de> Deserialize<'de> for InvokeCommand {
@@ -643,6 +963,10 @@ pub struct InvokeFocusCommand {
   #[clap(long)]
   direction: Option<Direction>,
 
+  /// Direction of the monitor to focus. Windows are not moved.
+  #[clap(long)]
+  monitor: Option<Direction>,
+
   #[clap(long)]
   workspace: Option<String>,
 
@@ -663,6 +987,10 @@ pub struct InvokeMoveCommand {
   #[clap(long)]
   direction: Option<Direction>,
 
+  /// Direction of the monitor to move the window to.
+  #[clap(long)]
+  monitor: Option<Direction>,
+
   /// Name of workspace to move the window.
   #[clap(long)]
   workspace: Option<String>,
@@ -2,6 +2,8 @@ use tracing::{info, warn};
 
 use crate::common::platform::NativeWindow;
 
+/// Reverts changes made by the WM to the given windows, so that they're
+/// left in their original state on exit.
 pub fn run_cleanup(managed_windows: Vec<NativeWindow>) {
   info!("Running WM state cleanup.",);
 
@@ -11,5 +13,11 @@ pub fn run_cleanup(managed_windows: Vec<NativeWindow>) {
     }
 
     _ = window.set_border_color(None);
+
+    // These only revert the opacity, title bar, and topmost state if they
+    // were changed by the WM.
+    _ = window.set_opacity(100);
+    _ = window.set_title_bar_visibility(true);
+    _ = window.set_topmost(false);
   }
 }
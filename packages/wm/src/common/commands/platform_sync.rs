@@ -8,7 +8,7 @@ use crate::{
     Container, WindowContainer,
   },
   user_config::{CursorJumpTrigger, UserConfig},
-  windows::traits::WindowGetters,
+  windows::{traits::WindowGetters, WindowState},
   wm_event::WmEvent,
   wm_state::WmState,
 };
@@ -18,7 +18,7 @@ pub fn platform_sync(
   config: &UserConfig,
 ) -> anyhow::Result<()> {
   if state.pending_sync.containers_to_redraw.len() > 0 {
-    redraw_containers(state)?;
+    redraw_containers(state, config)?;
     state.pending_sync.containers_to_redraw.clear();
   }
 
@@ -37,6 +37,7 @@ pub fn platform_sync(
   }
 
   if let Ok(window) = focused_container.as_window_container() {
+    state.push_window_history(window.id());
     apply_window_effects(window, true, config);
   }
 
@@ -93,7 +94,10 @@ fn sync_focus(
   Ok(())
 }
 
-fn redraw_containers(state: &mut WmState) -> anyhow::Result<()> {
+fn redraw_containers(
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
   for window in &state.windows_to_redraw() {
     let workspace =
       window.workspace().context("Window has no workspace.")?;
@@ -112,6 +116,27 @@ fn redraw_containers(state: &mut WmState) -> anyhow::Result<()> {
       },
     );
 
+    // Remove the title bar of tiling and fullscreen windows if enabled.
+    // This is done before getting the window's rect, since the border
+    // delta changes along with the title bar. Showing the title bar is a
+    // no-op unless it was previously removed by the WM.
+    let is_title_bar_visible = match window.state() {
+      WindowState::Tiling => {
+        !config.value.window_behavior.remove_tiling_title_bars
+      }
+      WindowState::Fullscreen(fullscreen_state) => {
+        !fullscreen_state.remove_title_bar
+      }
+      _ => true,
+    };
+
+    if let Err(err) = window
+      .native()
+      .set_title_bar_visibility(is_title_bar_visible)
+    {
+      warn!("Failed to set title bar visibility: {}", err);
+    }
+
     let rect =
       window.to_rect()?.apply_delta(&window.total_border_delta()?);
 
@@ -171,26 +196,38 @@ fn apply_window_effects(
   is_focused: bool,
   config: &UserConfig,
 ) {
-  // TODO: Be able to add transparency to windows.
-
   let window_effects = &config.value.window_effects;
 
-  // Skip if both focused + non-focused window effects are disabled.
-  if !window_effects.focused_window.border.enabled
-    && !window_effects.other_windows.border.enabled
-  {
-    return;
+  let effect_config = match is_focused {
+    true => &window_effects.focused_window,
+    false => &window_effects.other_windows,
   };
 
-  let border_config = match is_focused {
-    true => &config.value.window_effects.focused_window.border,
-    false => &config.value.window_effects.other_windows.border,
-  };
+  // Skip if both focused + non-focused border effects are disabled.
+  if window_effects.focused_window.border.enabled
+    || window_effects.other_windows.border.enabled
+  {
+    let border_color = match effect_config.border.enabled {
+      true => Some(&effect_config.border.color),
+      false => None,
+    };
 
-  let border_color = match border_config.enabled {
-    true => Some(&border_config.color),
-    false => None,
-  };
+    _ = window.native().set_border_color(border_color);
+  }
 
-  _ = window.native().set_border_color(border_color);
+  // Skip if both focused + non-focused transparency effects are disabled,
+  // and the window has no opacity override.
+  if window_effects.focused_window.transparency.enabled
+    || window_effects.other_windows.transparency.enabled
+    || window.opacity().is_some()
+  {
+    let effect_opacity = match effect_config.transparency.enabled {
+      true => effect_config.transparency.opacity,
+      false => 100,
+    };
+
+    let opacity = window.opacity().unwrap_or(effect_opacity);
+
+    _ = window.native().set_opacity(opacity);
+  }
 }
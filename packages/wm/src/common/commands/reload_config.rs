@@ -68,6 +68,15 @@ fn update_workspace_configs(
   for workspace in &workspaces {
     let monitor = workspace.monitor().context("No monitor.")?;
 
+    let is_defined = config
+      .workspace_config_index(&workspace.config().name)
+      .is_some();
+
+    // Dynamic workspaces don't have a config to update.
+    if !is_defined && config.value.general.dynamic_workspaces {
+      continue;
+    }
+
     let workspace_config = config
       .value
       .workspaces
@@ -118,7 +127,7 @@ fn update_container_gaps(state: &mut WmState, config: &UserConfig) {
   }
 
   for workspace in state.workspaces() {
-    workspace.set_outer_gap(config.value.gaps.outer_gap.clone());
+    workspace.set_outer_gap(config.outer_gap(&workspace.config()));
   }
 }
 
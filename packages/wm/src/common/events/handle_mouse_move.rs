@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use anyhow::Context;
 
 use crate::{
@@ -23,15 +25,61 @@ pub fn handle_mouse_move(
     .map(|root| state.window_from_native(&root))?;
 
   // Set focus to whichever window is currently under the cursor.
-  if let Some(window) = window_under_cursor {
-    let focused_container =
-      state.focused_container().context("No focused container.")?;
+  match window_under_cursor {
+    Some(window) => {
+      let focused_container =
+        state.focused_container().context("No focused container.")?;
+
+      if focused_container.id() == window.id() {
+        return Ok(());
+      }
+
+      let delay = Duration::from_millis(
+        config.value.general.focus_follows_cursor_delay_ms as u64,
+      );
+
+      // Get the time since the cursor started hovering over the window.
+      let hover_start = match state.cursor_hover_start {
+        Some((id, hover_start)) if id == window.id() => hover_start,
+        _ => {
+          let hover_start = Instant::now();
+          state.cursor_hover_start = Some((window.id(), hover_start));
+
+          // Events are only emitted on mouse movement, so schedule a
+          // re-check for when the delay has elapsed. This way, the
+          // window still gets focused if the cursor stops over it.
+          cancel_hover_timer(state);
+          if !delay.is_zero() {
+            state.cursor_hover_timer =
+              Some(Platform::emit_mouse_move_after(delay));
+          }
+
+          hover_start
+        }
+      };
 
-    if focused_container.id() != window.id() {
+      // Only focus the window once the cursor has been over it for long
+      // enough.
+      if hover_start.elapsed() < delay {
+        return Ok(());
+      }
+
+      cancel_hover_timer(state);
       set_focused_descendant(window.as_container(), None);
       state.pending_sync.focus_change = true;
     }
+    None => {
+      state.cursor_hover_start = None;
+      cancel_hover_timer(state);
+    }
   }
 
   Ok(())
 }
+
+/// Cancels the pending re-check of the window under the cursor (if any).
+fn cancel_hover_timer(state: &mut WmState) {
+  if let Some(timer) = state.cursor_hover_timer.take() {
+    timer.abort();
+  }
+}
@@ -1,8 +1,8 @@
 use tracing::info;
 
 use crate::{
-  common::platform::NativeWindow, windows::commands::unmanage_window,
-  wm_state::WmState,
+  common::platform::NativeWindow, containers::traits::CommonGetters,
+  windows::commands::unmanage_window, wm_state::WmState,
 };
 
 pub fn handle_window_destroyed(
@@ -11,11 +11,43 @@ pub fn handle_window_destroyed(
 ) -> anyhow::Result<()> {
   let found_window = state.window_from_native(&native_window);
 
+  // Stop ignoring the window, since its handle can get reused.
+  state
+    .ignored_windows
+    .retain(|ignored_window| *ignored_window != native_window);
+
+  // Remove any marks assigned to the window for the same reason.
+  state
+    .window_marks
+    .retain(|_, marked_window| *marked_window != native_window);
+
   // Unmanage the window if it's currently managed.
   if let Some(window) = found_window {
     // TODO: Log window details.
     info!("Window closed");
-    unmanage_window(window, state)?;
+    unmanage_window(window.clone(), state)?;
+
+    // Owned windows are destroyed along with their owner, so also
+    // unmanage any dialogs of the window. Otherwise, they'd linger in
+    // the tree if their own destroy event is missed.
+    let dialog_ids = state
+      .dialog_owners
+      .iter()
+      .filter(|(_, owner_id)| **owner_id == window.id())
+      .map(|(dialog_id, _)| *dialog_id)
+      .collect::<Vec<_>>();
+
+    for dialog_id in dialog_ids {
+      state.dialog_owners.remove(&dialog_id);
+
+      let dialog = state
+        .container_by_id(dialog_id)
+        .and_then(|container| container.as_window_container().ok());
+
+      if let Some(dialog) = dialog {
+        unmanage_window(dialog, state)?;
+      }
+    }
   }
 
   Ok(())
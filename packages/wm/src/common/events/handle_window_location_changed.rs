@@ -184,8 +184,14 @@ fn update_drag_state(
       ..active_drag
     }));
 
+    // Windows on a locked workspace are kept in the tree, and get
+    // redrawn back into their place when the drag ends.
+    let is_locked = window
+      .workspace()
+      .is_some_and(|workspace| workspace.is_locked());
+
     // Transition window to be floating while it's being dragged.
-    if is_move {
+    if is_move && !is_locked {
       let parent = window.parent().context("No parent")?;
 
       update_window_state(
@@ -201,7 +207,7 @@ fn update_drag_state(
       // Flatten the parent split container if it only contains the window.
       if let Some(split_parent) = parent.as_split() {
         if split_parent.child_count() == 1 {
-          flatten_split_container(split_parent.clone())?;
+          flatten_split_container(split_parent.clone(), state)?;
         }
       }
     }
@@ -59,9 +59,15 @@ pub fn handle_window_moved_or_resized_end(
 
         let parent = window.parent().context("No parent.")?;
 
+        let is_locked = window
+          .workspace()
+          .is_some_and(|workspace| workspace.is_locked());
+
         // Snap window to its original position if it's the only window in
-        // the workspace.
-        if parent.is_workspace() && window.tiling_siblings().count() == 0 {
+        // the workspace, or if the workspace's layout is locked.
+        if is_locked
+          || parent.is_workspace() && window.tiling_siblings().count() == 0
+        {
           state
             .pending_sync
             .containers_to_redraw
@@ -75,6 +81,7 @@ pub fn handle_window_moved_or_resized_end(
           Some(LengthValue::from_px(width_delta)),
           Some(LengthValue::from_px(height_delta)),
           state,
+          config,
         )?;
 
         state.pending_sync.containers_to_redraw.push(parent);
@@ -99,11 +106,17 @@ fn drop_as_tiling_window(
   let mouse_pos = Platform::mouse_position()?;
 
   // Get the workspace, split containers, and other windows under the
-  // dragged window.
+  // dragged window. Containers on locked workspaces can't be dropped
+  // into.
   let containers_at_pos = state
     .containers_at_point(&mouse_pos)
     .into_iter()
-    .filter(|container| container.id() != moved_window.id());
+    .filter(|container| container.id() != moved_window.id())
+    .filter(|container| {
+      !container
+        .workspace()
+        .is_some_and(|workspace| workspace.is_locked())
+    });
 
   let workspace = moved_window.workspace().context("No workspace.")?;
 
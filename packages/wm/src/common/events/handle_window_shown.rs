@@ -3,7 +3,10 @@ use tracing::info;
 use crate::{
   common::{platform::NativeWindow, DisplayState},
   user_config::UserConfig,
-  windows::{commands::manage_window, traits::WindowGetters},
+  windows::{
+    commands::{manage_window, unmanage_window},
+    traits::WindowGetters,
+  },
   wm_state::WmState,
 };
 
@@ -12,6 +15,12 @@ pub fn handle_window_shown(
   state: &mut WmState,
   config: &mut UserConfig,
 ) -> anyhow::Result<()> {
+  // Remove any windows that previously used the same handle.
+  for stale_window in state.stale_windows_from_native(&native_window) {
+    info!("Removing stale window with reused handle");
+    unmanage_window(stale_window, state)?;
+  }
+
   let found_window = state.window_from_native(&native_window);
 
   match found_window {
@@ -25,8 +34,10 @@ pub fn handle_window_shown(
       }
     }
     None => {
-      // If the window is not managed, manage it.
-      if native_window.is_manageable().unwrap_or(false) {
+      let is_ignored = state.ignored_windows.contains(&native_window);
+
+      // If the window is not managed or ignored, manage it.
+      if !is_ignored && native_window.is_manageable().unwrap_or(false) {
         manage_window(native_window, None, state, config)?;
       }
     }
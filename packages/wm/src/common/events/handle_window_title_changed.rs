@@ -16,11 +16,20 @@ pub fn handle_window_title_changed(
   let found_window = state.window_from_native(&native_window);
 
   if let Some(window) = found_window {
+    let old_title = try_warn!(window.native().title());
+    let title = try_warn!(window.native().refresh_title());
+
+    // Name change events are also emitted when the title is set to its
+    // current value. Skip these to avoid re-running window rules, which
+    // could otherwise loop if a rule's commands trigger a name change.
+    if title == old_title {
+      return Ok(());
+    }
+
     // TODO: Log window details.
     info!("Window title changed");
 
     // Run window rules for title change events.
-    try_warn!(window.native().refresh_title());
     run_window_rules(window, WindowRuleEvent::TitleChange, state, config)?;
   }
 
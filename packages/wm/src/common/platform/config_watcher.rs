@@ -0,0 +1,182 @@
+use std::{ffi::c_void, mem, path::Path, time::Duration};
+
+use anyhow::Context;
+use tokio::{
+  sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+  task::JoinHandle,
+  time,
+};
+use tracing::warn;
+use windows::{
+  core::HSTRING,
+  Win32::{
+    Foundation::{CloseHandle, HANDLE},
+    Storage::FileSystem::{
+      CreateFileW, ReadDirectoryChangesW, FILE_FLAG_BACKUP_SEMANTICS,
+      FILE_LIST_DIRECTORY, FILE_NOTIFY_CHANGE_FILE_NAME,
+      FILE_NOTIFY_CHANGE_LAST_WRITE, FILE_NOTIFY_INFORMATION,
+      FILE_SHARE_DELETE, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
+    },
+  },
+};
+
+/// Time without further changes before emitting a change. Editors often
+/// write a file in several steps, so this avoids reloading the config
+/// multiple times (or reading a partially written config) on one save.
+const CHANGE_DEBOUNCE: Duration = Duration::from_millis(200);
+
+pub struct ConfigWatcher {
+  pub change_rx: UnboundedReceiver<()>,
+  debounce_task: JoinHandle<()>,
+}
+
+impl ConfigWatcher {
+  /// Starts watching the given config file for changes.
+  ///
+  /// Changes are detected via `ReadDirectoryChangesW` on a separate
+  /// thread. The thread blocks until the next change, and is therefore
+  /// not joined on shutdown. It instead exits on the next change after
+  /// the watcher is dropped.
+  pub fn start(config_path: &Path) -> anyhow::Result<Self> {
+    let (raw_change_tx, raw_change_rx) = mpsc::unbounded_channel();
+    let (change_tx, change_rx) = mpsc::unbounded_channel();
+
+    let config_dir = config_path
+      .parent()
+      .context("Invalid config path.")?
+      .to_owned();
+
+    let config_file_name = config_path
+      .file_name()
+      .context("Invalid config path.")?
+      .to_string_lossy()
+      .to_string();
+
+    let dir_handle = unsafe {
+      CreateFileW(
+        &HSTRING::from(config_dir.as_path()),
+        FILE_LIST_DIRECTORY.0,
+        FILE_SHARE_READ | FILE_SHARE_WRITE | FILE_SHARE_DELETE,
+        None,
+        OPEN_EXISTING,
+        FILE_FLAG_BACKUP_SEMANTICS,
+        HANDLE(0),
+      )
+    }
+    .context("Failed to open config directory.")?;
+
+    std::thread::spawn(move || {
+      if let Err(err) =
+        Self::watch(dir_handle, &config_file_name, &raw_change_tx)
+      {
+        warn!("Stopped watching config file: {:?}", err);
+      }
+
+      unsafe {
+        let _ = CloseHandle(dir_handle);
+      }
+    });
+
+    let debounce_task =
+      tokio::spawn(Self::debounce(raw_change_rx, change_tx));
+
+    Ok(Self {
+      change_rx,
+      debounce_task,
+    })
+  }
+
+  /// Emits a single change once no further raw changes have been
+  /// received for the duration of `CHANGE_DEBOUNCE`.
+  async fn debounce(
+    mut raw_change_rx: UnboundedReceiver<()>,
+    change_tx: UnboundedSender<()>,
+  ) {
+    while raw_change_rx.recv().await.is_some() {
+      loop {
+        match time::timeout(CHANGE_DEBOUNCE, raw_change_rx.recv()).await {
+          Ok(Some(_)) => continue,
+          Ok(None) => return,
+          Err(_) => break,
+        }
+      }
+
+      if change_tx.send(()).is_err() {
+        return;
+      }
+    }
+  }
+
+  /// Blocks and emits on the channel whenever the config file changes.
+  fn watch(
+    dir_handle: HANDLE,
+    config_file_name: &str,
+    change_tx: &UnboundedSender<()>,
+  ) -> anyhow::Result<()> {
+    // Use a `u32` buffer, since `FILE_NOTIFY_INFORMATION` entries need to
+    // be DWORD-aligned.
+    let mut buffer = [0u32; 1024];
+
+    loop {
+      let mut bytes_returned = 0u32;
+
+      unsafe {
+        ReadDirectoryChangesW(
+          dir_handle,
+          buffer.as_mut_ptr() as *mut c_void,
+          mem::size_of_val(&buffer) as u32,
+          false,
+          FILE_NOTIFY_CHANGE_LAST_WRITE | FILE_NOTIFY_CHANGE_FILE_NAME,
+          Some(&mut bytes_returned),
+          None,
+          None,
+        )
+      }?;
+
+      // Zero bytes are returned if the buffer overflowed, in which case
+      // the changed file names are unknown.
+      let has_changed = bytes_returned == 0
+        || Self::changed_file_names(&buffer).iter().any(|file_name| {
+          Path::new(file_name) == Path::new(config_file_name)
+        });
+
+      if has_changed {
+        change_tx.send(())?;
+      }
+    }
+  }
+
+  /// Gets the file names from a buffer of `FILE_NOTIFY_INFORMATION`
+  /// entries.
+  fn changed_file_names(buffer: &[u32]) -> Vec<String> {
+    let mut file_names = Vec::new();
+    let mut offset = 0usize;
+
+    loop {
+      let info = unsafe {
+        &*((buffer.as_ptr() as *const u8).add(offset)
+          as *const FILE_NOTIFY_INFORMATION)
+      };
+
+      let name_len = info.FileNameLength as usize / mem::size_of::<u16>();
+      let name = unsafe {
+        std::slice::from_raw_parts(info.FileName.as_ptr(), name_len)
+      };
+
+      file_names.push(String::from_utf16_lossy(name));
+
+      match info.NextEntryOffset {
+        0 => break,
+        next_offset => offset += next_offset as usize,
+      }
+    }
+
+    file_names
+  }
+}
+
+impl Drop for ConfigWatcher {
+  fn drop(&mut self) {
+    self.debounce_task.abort();
+  }
+}
@@ -4,10 +4,10 @@ use std::{
     Arc, OnceLock,
   },
   thread::{self, JoinHandle},
-  time::SystemTime,
+  time::{Duration, SystemTime},
 };
 
-use tokio::sync::mpsc;
+use tokio::{sync::mpsc, task};
 use tracing::{info, warn};
 use windows::Win32::{
   Devices::HumanInterfaceDevice::{
@@ -138,6 +138,22 @@ impl EventWindow {
     ENABLE_MOUSE_EVENTS.store(enable_mouse_events, Ordering::Relaxed);
   }
 
+  /// Emits a `PlatformEvent::MouseMove` for the current cursor position
+  /// after the given delay, regardless of whether the mouse has moved.
+  ///
+  /// Returns a handle that can be aborted to cancel the emission.
+  pub fn emit_mouse_move_after(delay: Duration) -> task::JoinHandle<()> {
+    task::spawn(async move {
+      tokio::time::sleep(delay).await;
+
+      if let Some(event_tx) = PLATFORM_EVENT_TX.get() {
+        if let Err(err) = emit_mouse_move(event_tx) {
+          warn!("Failed to emit delayed mouse move event: {:?}", err);
+        }
+      }
+    })
+  }
+
   /// Destroys the event window and stops the message loop.
   pub fn destroy(&mut self) -> anyhow::Result<()> {
     info!("Shutting down event window.");
@@ -285,6 +301,17 @@ fn handle_input_msg(
     return Ok(());
   }
 
+  emit_mouse_move(event_tx)?;
+  LAST_MOUSE_EVENT_TIME.store(event_time, Ordering::Relaxed);
+
+  Ok(())
+}
+
+/// Emits a `PlatformEvent::MouseMove` with the current cursor position
+/// and mouse button state.
+fn emit_mouse_move(
+  event_tx: &mpsc::UnboundedSender<PlatformEvent>,
+) -> anyhow::Result<()> {
   let is_mouse_down = IS_L_MOUSE_DOWN.load(Ordering::Relaxed)
     || IS_R_MOUSE_DOWN.load(Ordering::Relaxed);
 
@@ -299,8 +326,6 @@ fn handle_input_msg(
     is_mouse_down,
   }))?;
 
-  LAST_MOUSE_EVENT_TIME.store(event_time, Ordering::Relaxed);
-
   Ok(())
 }
 
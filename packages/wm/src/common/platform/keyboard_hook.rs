@@ -136,7 +136,11 @@ impl KeyboardHook {
     keybinding_map
   }
 
-  fn key_to_vk_code(key: &str) -> Option<u16> {
+  /// Gets the virtual key code for a key name in a keybinding (e.g.
+  /// `alt` or `a`).
+  ///
+  /// Returns `None` if the key isn't recognized on the current keyboard.
+  pub fn key_to_vk_code(key: &str) -> Option<u16> {
     match key.to_lowercase().as_str() {
       "a" => Some(VK_A.0),
       "b" => Some(VK_B.0),
@@ -1,3 +1,4 @@
+mod config_watcher;
 mod event_listener;
 mod event_window;
 mod keyboard_hook;
@@ -6,7 +7,9 @@ mod native_window;
 mod platform;
 mod single_instance;
 mod window_event_hook;
+mod window_labels;
 
+pub use config_watcher::*;
 pub use event_listener::*;
 pub use event_window::*;
 pub use keyboard_hook::*;
@@ -15,3 +18,4 @@ pub use native_window::*;
 pub use platform::*;
 pub use single_instance::*;
 pub use window_event_hook::*;
+pub use window_labels::*;
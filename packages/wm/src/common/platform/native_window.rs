@@ -1,16 +1,36 @@
+use std::{
+  hash::{Hash, Hasher},
+  mem, ptr, slice,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+};
+
 use anyhow::Context;
 use tracing::warn;
 use windows::{
-  core::PWSTR,
+  core::{HSTRING, PWSTR},
   Win32::{
-    Foundation::{CloseHandle, BOOL, HWND, LPARAM, RECT},
-    Graphics::Dwm::{
-      DwmGetWindowAttribute, DwmSetWindowAttribute, DWMWA_BORDER_COLOR,
-      DWMWA_CLOAKED, DWMWA_COLOR_NONE, DWMWA_EXTENDED_FRAME_BOUNDS,
+    Foundation::{
+      CloseHandle, BOOL, COLORREF, FILETIME, HANDLE, HWND, LPARAM, RECT,
+      WPARAM,
+    },
+    Graphics::{
+      Dwm::{
+        DwmGetWindowAttribute, DwmSetWindowAttribute, DWMWA_BORDER_COLOR,
+        DWMWA_CLOAKED, DWMWA_COLOR_NONE, DWMWA_EXTENDED_FRAME_BOUNDS,
+      },
+      Gdi::{
+        CreateCompatibleDC, CreateDIBSection, DeleteDC, DeleteObject,
+        GdiFlush, SelectObject, BITMAPINFO, BITMAPINFOHEADER, BI_RGB,
+        DIB_RGB_COLORS, HBRUSH, HDC,
+      },
     },
     System::Threading::{
-      OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
-      PROCESS_QUERY_INFORMATION,
+      GetProcessTimes, OpenProcess, QueryFullProcessImageNameW,
+      PROCESS_NAME_WIN32, PROCESS_QUERY_INFORMATION,
+      PROCESS_QUERY_LIMITED_INFORMATION,
     },
     UI::{
       Input::KeyboardAndMouse::{
@@ -18,35 +38,52 @@ use windows::{
         KEYBD_EVENT_FLAGS, VIRTUAL_KEY,
       },
       WindowsAndMessaging::{
-        EnumWindows, GetClassNameW, GetWindow, GetWindowLongPtrW,
-        GetWindowRect, GetWindowTextW, GetWindowThreadProcessId, IsIconic,
-        IsWindowVisible, IsZoomed, SendNotifyMessageW,
-        SetForegroundWindow, SetWindowPos, ShowWindowAsync, GWL_EXSTYLE,
-        GWL_STYLE, GW_OWNER, HWND_NOTOPMOST, HWND_TOPMOST,
-        SWP_ASYNCWINDOWPOS, SWP_FRAMECHANGED, SWP_HIDEWINDOW,
-        SWP_NOACTIVATE, SWP_NOCOPYBITS, SWP_NOSENDCHANGING,
-        SWP_SHOWWINDOW, SW_HIDE, SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE,
-        SW_SHOWNA, WINDOW_EX_STYLE, WINDOW_STYLE, WM_CLOSE, WS_CAPTION,
-        WS_CHILD, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_MAXIMIZEBOX,
-        WS_THICKFRAME,
+        DrawIconEx, EnumWindows, GetClassLongPtrW, GetClassNameW,
+        GetWindow, GetWindowLongPtrW, GetWindowRect, GetWindowTextW,
+        GetWindowThreadProcessId, IsIconic, IsWindowVisible, IsZoomed,
+        SendMessageTimeoutW, SendNotifyMessageW, SetForegroundWindow,
+        SetLayeredWindowAttributes, SetWindowLongPtrW, SetWindowPos,
+        SetWindowTextW, ShowWindowAsync, DI_NORMAL, GCLP_HICON,
+        GCLP_HICONSM, GWL_EXSTYLE, GWL_STYLE, GW_OWNER, HICON,
+        HWND_NOTOPMOST, HWND_TOPMOST, ICON_BIG, ICON_SMALL, ICON_SMALL2,
+        LWA_ALPHA, SMTO_ABORTIFHUNG, SWP_ASYNCWINDOWPOS, SWP_FRAMECHANGED,
+        SWP_HIDEWINDOW, SWP_NOACTIVATE, SWP_NOCOPYBITS, SWP_NOMOVE,
+        SWP_NOSENDCHANGING, SWP_NOSIZE, SWP_NOZORDER, SWP_SHOWWINDOW,
+        SW_HIDE, SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE, SW_SHOWNA,
+        WINDOW_EX_STYLE, WINDOW_STYLE, WM_CLOSE, WM_GETICON, WS_CAPTION,
+        WS_CHILD, WS_EX_DLGMODALFRAME, WS_EX_LAYERED, WS_EX_NOACTIVATE,
+        WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT,
+        WS_MAXIMIZEBOX, WS_THICKFRAME,
       },
     },
   },
 };
 
+use super::native_monitor;
 use crate::{
   common::{Color, LengthValue, Memo, Rect, RectDelta},
   windows::WindowState,
 };
 
+/// Width and height (in pixels) of icons retrieved via
+/// `NativeWindow::icon`.
+const ICON_SIZE: i32 = 32;
+
 #[derive(Debug, Clone)]
 pub struct NativeWindow {
   pub handle: isize,
   title: Memo<String>,
+  process_id: Memo<u32>,
+  process_creation_time: Memo<u64>,
   process_name: Memo<String>,
   class_name: Memo<String>,
   frame_position: Memo<Rect>,
   border_position: Memo<Rect>,
+  original_style: Memo<isize>,
+  original_ex_style: Memo<isize>,
+  has_removed_title_bar: Arc<AtomicBool>,
+  has_changed_opacity: Arc<AtomicBool>,
+  has_set_topmost: Arc<AtomicBool>,
   is_minimized: Memo<bool>,
   is_maximized: Memo<bool>,
 }
@@ -57,10 +94,17 @@ impl NativeWindow {
     Self {
       handle,
       title: Memo::new(),
+      process_id: Memo::new(),
+      process_creation_time: Memo::new(),
       process_name: Memo::new(),
       class_name: Memo::new(),
       frame_position: Memo::new(),
       border_position: Memo::new(),
+      original_style: Memo::new(),
+      original_ex_style: Memo::new(),
+      has_removed_title_bar: Arc::new(AtomicBool::new(false)),
+      has_changed_opacity: Arc::new(AtomicBool::new(false)),
+      has_set_topmost: Arc::new(AtomicBool::new(false)),
       is_minimized: Memo::new(),
       is_maximized: Memo::new(),
     }
@@ -87,6 +131,77 @@ impl NativeWindow {
     Ok(String::from_utf16_lossy(&text[..length as usize]))
   }
 
+  /// Sets the window's title and updates the cached title.
+  pub fn set_title(&self, title: &str) -> anyhow::Result<()> {
+    unsafe { SetWindowTextW(HWND(self.handle), &HSTRING::from(title)) }?;
+    self.refresh_title()?;
+    Ok(())
+  }
+
+  /// Gets the ID of the process that created the window.
+  ///
+  /// This value is lazily retrieved and cached after first retrieval.
+  pub fn process_id(&self) -> anyhow::Result<u32> {
+    self.process_id.get_or_init(Self::updated_process_id, self)
+  }
+
+  /// Gets the ID of the process that created the window.
+  fn updated_process_id(&self) -> anyhow::Result<u32> {
+    let mut process_id = 0u32;
+    unsafe {
+      GetWindowThreadProcessId(HWND(self.handle), Some(&mut process_id));
+    }
+
+    match process_id {
+      0 => Err(windows::core::Error::from_win32().into()),
+      _ => Ok(process_id),
+    }
+  }
+
+  /// Gets the creation time of the process that created the window, as a
+  /// `FILETIME` value.
+  ///
+  /// This value is lazily retrieved and cached after first retrieval.
+  pub fn process_creation_time(&self) -> anyhow::Result<u64> {
+    self
+      .process_creation_time
+      .get_or_init(Self::updated_process_creation_time, self)
+  }
+
+  /// Gets the creation time of the process that created the window.
+  fn updated_process_creation_time(&self) -> anyhow::Result<u64> {
+    let process_id = self.process_id()?;
+
+    // Limited access is enough for `GetProcessTimes`, and is also granted
+    // for elevated processes.
+    let process_handle = unsafe {
+      OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id)
+    }?;
+
+    let mut creation_time = FILETIME::default();
+    let mut exit_time = FILETIME::default();
+    let mut kernel_time = FILETIME::default();
+    let mut user_time = FILETIME::default();
+    let res = unsafe {
+      GetProcessTimes(
+        process_handle,
+        &mut creation_time,
+        &mut exit_time,
+        &mut kernel_time,
+        &mut user_time,
+      )
+    };
+
+    // Close the handle before propagating any error.
+    unsafe { CloseHandle(process_handle) }?;
+    res?;
+
+    Ok(
+      ((creation_time.dwHighDateTime as u64) << 32)
+        | creation_time.dwLowDateTime as u64,
+    )
+  }
+
   /// Gets the process name associated with the window.
   ///
   /// This value is lazily retrieved and cached after first retrieval.
@@ -98,10 +213,7 @@ impl NativeWindow {
 
   /// Gets the process name associated with the window.
   fn updated_process_name(&self) -> anyhow::Result<String> {
-    let mut process_id = 0u32;
-    unsafe {
-      GetWindowThreadProcessId(HWND(self.handle), Some(&mut process_id));
-    }
+    let process_id = self.process_id()?;
 
     let process_handle = unsafe {
       OpenProcess(PROCESS_QUERY_INFORMATION, false, process_id)
@@ -178,12 +290,86 @@ impl NativeWindow {
     Ok(cloaked != 0)
   }
 
+  /// Whether the window lets mouse input pass through to the windows
+  /// underneath it (i.e. has the `WS_EX_TRANSPARENT` style).
+  pub fn is_transparent(&self) -> bool {
+    self.has_window_style_ex(WS_EX_TRANSPARENT)
+  }
+
+  /// Gets the owner window (e.g. the main window of a dialog), if there
+  /// is one.
+  pub fn owner(&self) -> Option<NativeWindow> {
+    let owner_handle = unsafe { GetWindow(HWND(self.handle), GW_OWNER) };
+
+    match owner_handle.0 {
+      0 => None,
+      handle => Some(NativeWindow::new(handle)),
+    }
+  }
+
+  /// Whether the window is a dialog, i.e. it has a visible owner window
+  /// or a dialog frame (e.g. message boxes).
+  ///
+  /// Hidden owners are ignored, since some frameworks (e.g. WinForms and
+  /// Delphi) give every top-level window a hidden owner.
+  pub fn is_dialog(&self) -> bool {
+    let has_visible_owner = self
+      .owner()
+      .is_some_and(|owner| owner.is_visible().unwrap_or(false));
+
+    has_visible_owner || self.has_window_style_ex(WS_EX_DLGMODALFRAME)
+  }
+
+  /// Whether the window is an app bar, i.e. the taskbar on the primary
+  /// or secondary monitors, or a third-party bar or dock that reserves
+  /// screen space via `SHAppBarMessage`.
+  ///
+  /// The space reserved by app bars is already excluded from the
+  /// monitor's working area.
+  pub fn is_app_bar(&self) -> anyhow::Result<bool> {
+    let class_name = self.class_name()?;
+
+    if matches!(
+      class_name.as_str(),
+      "Shell_TrayWnd" | "Shell_SecondaryTrayWnd"
+    ) {
+      return Ok(true);
+    }
+
+    // There's no API for getting the registered app bars, so instead
+    // check for a topmost window that is docked to a monitor edge and
+    // lies fully within the reserved area outside the working area.
+    if !self.has_window_style_ex(WS_EX_TOPMOST) {
+      return Ok(false);
+    }
+
+    let monitor = native_monitor::nearest_monitor(self.handle);
+    let monitor_rect = monitor.rect()?;
+    let working_rect = monitor.working_rect()?;
+    let frame = self.frame_position()?;
+
+    let is_docked = frame.left == monitor_rect.left
+      || frame.top == monitor_rect.top
+      || frame.right == monitor_rect.right
+      || frame.bottom == monitor_rect.bottom;
+
+    let is_in_reserved_area = !(frame.has_overlap_x(working_rect)
+      && frame.has_overlap_y(working_rect));
+
+    Ok(is_docked && is_in_reserved_area)
+  }
+
   pub fn is_manageable(&self) -> anyhow::Result<bool> {
     // Ignore windows that are hidden.
     if !self.is_visible()? {
       return Ok(false);
     }
 
+    // Ignore app bars, since their position is managed by the shell.
+    if self.is_app_bar()? {
+      return Ok(false);
+    }
+
     let process_name = self.process_name()?;
     let title = self.process_name()?;
 
@@ -208,8 +394,7 @@ impl NativeWindow {
     // bar menu in Keepass. Although not foolproof, these can typically be
     // identified by having an owner window and no title bar.
     let is_menu_window =
-      unsafe { GetWindow(HWND(self.handle), GW_OWNER) }.0 != 0
-        && !self.has_window_style(WS_CAPTION);
+      self.owner().is_some() && !self.has_window_style(WS_CAPTION);
 
     Ok(!is_menu_window)
   }
@@ -421,6 +606,212 @@ impl NativeWindow {
     ))
   }
 
+  /// Gets the window's icon as a PNG-encoded 32x32 image.
+  ///
+  /// Uses the icon set on the window if available, and otherwise falls
+  /// back to the icon of its window class.
+  pub fn icon(&self) -> anyhow::Result<Vec<u8>> {
+    let icon = self.icon_handle().context("Window has no icon.")?;
+    let pixels = Self::icon_pixels(icon)?;
+
+    let mut png_bytes = Vec::new();
+    let mut encoder = png::Encoder::new(
+      &mut png_bytes,
+      ICON_SIZE as u32,
+      ICON_SIZE as u32,
+    );
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+
+    Ok(png_bytes)
+  }
+
+  /// Gets the handle of the window's icon, if it has one.
+  fn icon_handle(&self) -> Option<HICON> {
+    let window_icon = [ICON_BIG, ICON_SMALL2, ICON_SMALL]
+      .into_iter()
+      .find_map(|icon_type| {
+        let mut icon = 0;
+
+        // Use a timeout, since the message is otherwise blocking if the
+        // window is unresponsive.
+        let res = unsafe {
+          SendMessageTimeoutW(
+            HWND(self.handle),
+            WM_GETICON,
+            WPARAM(icon_type as usize),
+            LPARAM(0),
+            SMTO_ABORTIFHUNG,
+            100,
+            Some(&mut icon),
+          )
+        };
+
+        (res.0 != 0 && icon != 0).then_some(icon)
+      });
+
+    window_icon
+      .or_else(|| {
+        [GCLP_HICON, GCLP_HICONSM].into_iter().find_map(|index| {
+          let icon = unsafe { GetClassLongPtrW(HWND(self.handle), index) };
+          (icon != 0).then_some(icon)
+        })
+      })
+      .map(|icon| HICON(icon as isize))
+  }
+
+  /// Draws the given icon to a bitmap and returns its RGBA pixels.
+  fn icon_pixels(icon: HICON) -> anyhow::Result<Vec<u8>> {
+    let bitmap_info = BITMAPINFO {
+      bmiHeader: BITMAPINFOHEADER {
+        biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
+        biWidth: ICON_SIZE,
+        // Negative height to get a top-down bitmap.
+        biHeight: -ICON_SIZE,
+        biPlanes: 1,
+        biBitCount: 32,
+        biCompression: BI_RGB.0,
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+
+    let mut bits = ptr::null_mut();
+    let dc = unsafe { CreateCompatibleDC(HDC(0)) };
+
+    let pixels = unsafe {
+      CreateDIBSection(
+        dc,
+        &bitmap_info,
+        DIB_RGB_COLORS,
+        &mut bits,
+        HANDLE(0),
+        0,
+      )
+    }
+    .and_then(|bitmap| unsafe {
+      let previous_object = SelectObject(dc, bitmap);
+
+      let res = DrawIconEx(
+        dc,
+        0,
+        0,
+        icon,
+        ICON_SIZE,
+        ICON_SIZE,
+        0,
+        HBRUSH(0),
+        DI_NORMAL,
+      )
+      .map(|_| {
+        GdiFlush();
+        slice::from_raw_parts(
+          bits as *const u8,
+          (ICON_SIZE * ICON_SIZE * 4) as usize,
+        )
+        .to_vec()
+      });
+
+      SelectObject(dc, previous_object);
+      DeleteObject(bitmap);
+      res
+    });
+
+    unsafe { DeleteDC(dc) };
+    let mut pixels = pixels.context("Failed to draw icon.")?;
+
+    // Legacy icons without an alpha channel are drawn fully transparent,
+    // in which case they're made opaque instead.
+    let has_alpha = pixels.chunks_exact(4).any(|pixel| pixel[3] != 0);
+
+    // Convert from BGRA to RGBA.
+    for pixel in pixels.chunks_exact_mut(4) {
+      pixel.swap(0, 2);
+
+      if !has_alpha {
+        pixel[3] = 255;
+      }
+    }
+
+    Ok(pixels)
+  }
+
+  /// Sets the opacity of the window as a percentage (0-100).
+  ///
+  /// This adds the `WS_EX_LAYERED` style to the window if it doesn't
+  /// already have it. The style is removed again when the window is made
+  /// fully opaque, unless the window originally had it.
+  ///
+  /// Making the window fully opaque does nothing if its opacity was never
+  /// changed by the WM. Otherwise, windows that are already layered and
+  /// draw via `UpdateLayeredWindow` would stop rendering.
+  pub fn set_opacity(&self, opacity: u8) -> anyhow::Result<()> {
+    if opacity >= 100 && !self.has_changed_opacity.load(Ordering::Relaxed)
+    {
+      return Ok(());
+    }
+
+    // Cache the extended style from before the opacity was first changed.
+    let original_ex_style = self.original_ex_style.get_or_init(
+      |window| {
+        Ok(unsafe { GetWindowLongPtrW(HWND(window.handle), GWL_EXSTYLE) })
+      },
+      self,
+    )?;
+
+    let is_originally_layered =
+      (original_ex_style & WS_EX_LAYERED.0 as isize) != 0;
+
+    if !self.has_window_style_ex(WS_EX_LAYERED) {
+      if opacity >= 100 {
+        return Ok(());
+      }
+
+      unsafe {
+        let ex_style = GetWindowLongPtrW(HWND(self.handle), GWL_EXSTYLE);
+
+        SetWindowLongPtrW(
+          HWND(self.handle),
+          GWL_EXSTYLE,
+          ex_style | WS_EX_LAYERED.0 as isize,
+        );
+      }
+    }
+
+    if opacity >= 100 && !is_originally_layered {
+      unsafe {
+        let ex_style = GetWindowLongPtrW(HWND(self.handle), GWL_EXSTYLE);
+
+        SetWindowLongPtrW(
+          HWND(self.handle),
+          GWL_EXSTYLE,
+          ex_style & !(WS_EX_LAYERED.0 as isize),
+        );
+      }
+
+      self.has_changed_opacity.store(false, Ordering::Relaxed);
+      return Ok(());
+    }
+
+    let alpha = (opacity.min(100) as u32 * 255 / 100) as u8;
+
+    unsafe {
+      SetLayeredWindowAttributes(
+        HWND(self.handle),
+        COLORREF(0),
+        alpha,
+        LWA_ALPHA,
+      )
+    }?;
+
+    self
+      .has_changed_opacity
+      .store(opacity < 100, Ordering::Relaxed);
+
+    Ok(())
+  }
+
   fn has_window_style(&self, style: WINDOW_STYLE) -> bool {
     let current_style =
       unsafe { GetWindowLongPtrW(HWND(self.handle), GWL_STYLE) };
@@ -435,6 +826,104 @@ impl NativeWindow {
     (current_style & style.0 as isize) != 0
   }
 
+  /// Adds or removes the title bar and resize borders of the window.
+  ///
+  /// When shown again, these are only re-added if they were previously
+  /// removed by the WM and the window originally had them. This leaves
+  /// windows that remove their own frame (e.g. browsers in fullscreen)
+  /// untouched.
+  pub fn set_title_bar_visibility(
+    &self,
+    is_visible: bool,
+  ) -> anyhow::Result<()> {
+    if is_visible && !self.has_removed_title_bar.load(Ordering::Relaxed) {
+      return Ok(());
+    }
+
+    let title_bar_style = (WS_CAPTION | WS_THICKFRAME).0 as isize;
+
+    // Cache the style from before the title bar was first modified.
+    let original_style = self.original_style.get_or_init(
+      |window| {
+        Ok(unsafe { GetWindowLongPtrW(HWND(window.handle), GWL_STYLE) })
+      },
+      self,
+    )?;
+
+    let current_style =
+      unsafe { GetWindowLongPtrW(HWND(self.handle), GWL_STYLE) };
+
+    let new_style = match is_visible {
+      true => current_style | (original_style & title_bar_style),
+      false => current_style & !title_bar_style,
+    };
+
+    if new_style != current_style {
+      unsafe {
+        SetWindowLongPtrW(HWND(self.handle), GWL_STYLE, new_style);
+
+        // Apply the style change, since the frame is otherwise only
+        // redrawn on the next move.
+        SetWindowPos(
+          HWND(self.handle),
+          HWND_NOTOPMOST,
+          0,
+          0,
+          0,
+          0,
+          SWP_FRAMECHANGED
+            | SWP_NOMOVE
+            | SWP_NOSIZE
+            | SWP_NOZORDER
+            | SWP_NOACTIVATE,
+        )
+      }?;
+
+      // The shadow borders change along with the window style, so the
+      // cached positions need to be refreshed.
+      self.refresh_frame_position()?;
+      self.refresh_border_position()?;
+    }
+
+    self
+      .has_removed_title_bar
+      .store(!is_visible, Ordering::Relaxed);
+
+    Ok(())
+  }
+
+  /// Sets whether the window is shown above all other windows.
+  ///
+  /// Removing topmost does nothing unless the window was made topmost by
+  /// the WM. This leaves windows that are always on top by themselves
+  /// (e.g. picture-in-picture players) untouched.
+  pub fn set_topmost(&self, is_topmost: bool) -> anyhow::Result<()> {
+    if !is_topmost && !self.has_set_topmost.load(Ordering::Relaxed) {
+      return Ok(());
+    }
+
+    let z_order = match is_topmost {
+      true => HWND_TOPMOST,
+      false => HWND_NOTOPMOST,
+    };
+
+    unsafe {
+      SetWindowPos(
+        HWND(self.handle),
+        z_order,
+        0,
+        0,
+        0,
+        0,
+        SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+      )
+    }?;
+
+    self.has_set_topmost.store(is_topmost, Ordering::Relaxed);
+
+    Ok(())
+  }
+
   pub fn restore(&self) -> anyhow::Result<()> {
     unsafe { ShowWindowAsync(HWND(self.handle), SW_RESTORE).ok() }?;
     Ok(())
@@ -581,6 +1070,14 @@ impl NativeWindow {
       }
     };
 
+    // Keep track of whether the window was made topmost, so that this can
+    // be reverted on cleanup.
+    if state != &WindowState::Minimized {
+      self
+        .has_set_topmost
+        .store(z_order == HWND_TOPMOST, Ordering::Relaxed);
+    }
+
     Ok(())
   }
 }
@@ -593,11 +1090,55 @@ impl PartialEq for NativeWindow {
 
 impl Eq for NativeWindow {}
 
+impl Hash for NativeWindow {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.handle.hash(state);
+  }
+}
+
+/// Gets all top-level windows, excluding ones that should never be
+/// managed. This includes the desktop windows (`Progman` and `WorkerW`),
+/// zero-sized windows, and windows belonging to the WM process itself.
 pub fn available_windows() -> anyhow::Result<Vec<NativeWindow>> {
-  available_window_handles()?
-    .into_iter()
-    .map(|handle| Ok(NativeWindow::new(handle)))
-    .collect()
+  let wm_process_id = std::process::id();
+
+  Ok(
+    available_window_handles()?
+      .into_iter()
+      .map(NativeWindow::new)
+      .filter(|window| {
+        let is_desktop = window
+          .class_name()
+          .map(|class_name| {
+            matches!(class_name.as_str(), "Progman" | "WorkerW")
+          })
+          .unwrap_or(true);
+
+        let is_zero_sized = window
+          .frame_position()
+          .map(|rect| rect.width() == 0 || rect.height() == 0)
+          .unwrap_or(true);
+
+        let is_wm_window = window
+          .process_id()
+          .map(|process_id| process_id == wm_process_id)
+          .unwrap_or(true);
+
+        !is_desktop && !is_zero_sized && !is_wm_window
+      })
+      .collect(),
+  )
+}
+
+/// Gets all top-level windows that the WM is most likely able to manage.
+pub fn available_manageable_windows() -> anyhow::Result<Vec<NativeWindow>>
+{
+  Ok(
+    available_windows()?
+      .into_iter()
+      .filter(|window| window.is_manageable().unwrap_or(false))
+      .collect(),
+  )
 }
 
 pub fn available_window_handles() -> anyhow::Result<Vec<isize>> {
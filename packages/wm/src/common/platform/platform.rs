@@ -2,6 +2,7 @@ use std::{
   os::windows::io::AsRawHandle,
   path::{Path, PathBuf},
   thread::JoinHandle,
+  time::Duration,
 };
 
 use anyhow::{bail, Context};
@@ -19,24 +20,27 @@ use windows::{
       },
       WindowsAndMessaging::{
         CreateWindowExW, DispatchMessageW, GetAncestor, GetCursorPos,
-        GetDesktopWindow, GetForegroundWindow, GetMessageW, MessageBoxW,
-        PeekMessageW, PostThreadMessageW, RegisterClassW, SetCursorPos,
-        SystemParametersInfoW, TranslateMessage, WindowFromPoint,
-        ANIMATIONINFO, CS_HREDRAW, CS_VREDRAW, CW_USEDEFAULT, GA_ROOT,
-        MB_ICONERROR, MB_OK, MB_SYSTEMMODAL, MSG, PM_REMOVE,
-        SPI_GETANIMATION, SPI_SETANIMATION, SW_NORMAL,
-        SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS, WM_QUIT, WNDCLASSW, WNDPROC,
-        WS_OVERLAPPEDWINDOW,
+        GetDesktopWindow, GetForegroundWindow, GetMessageW, GetWindow,
+        MessageBoxW, PeekMessageW, PostThreadMessageW, RegisterClassW,
+        SetCursorPos, SystemParametersInfoW, TranslateMessage,
+        WindowFromPoint, ANIMATIONINFO, CS_HREDRAW, CS_VREDRAW,
+        CW_USEDEFAULT, GA_ROOT, GW_HWNDNEXT, MB_ICONERROR, MB_OK,
+        MB_SYSTEMMODAL, MSG, PM_REMOVE, SPI_GETANIMATION,
+        SPI_SETANIMATION, SW_NORMAL, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+        WM_QUIT, WNDCLASSW, WNDPROC, WS_OVERLAPPEDWINDOW,
       },
     },
   },
 };
 
 use super::{
-  native_monitor, native_window, EventListener, NativeMonitor,
-  NativeWindow, SingleInstance,
+  native_monitor, native_window, ConfigWatcher, EventListener,
+  EventWindow, NativeMonitor, NativeWindow, SingleInstance, WindowLabels,
+};
+use crate::{
+  common::{Point, Rect},
+  user_config::UserConfig,
 };
-use crate::{common::Point, user_config::UserConfig};
 
 pub type WindowProcedure = WNDPROC;
 
@@ -99,12 +103,7 @@ impl Platform {
   /// Manageable windows are visible windows that the WM is most likely
   /// able to manage.
   pub fn manageable_windows() -> anyhow::Result<Vec<NativeWindow>> {
-    Ok(
-      native_window::available_windows()?
-        .into_iter()
-        .filter(|window| window.is_manageable().unwrap_or(false))
-        .collect(),
-    )
+    native_window::available_manageable_windows()
   }
 
   /// Creates a new `EventListener` for the specified user config.
@@ -114,11 +113,37 @@ impl Platform {
     EventListener::start(config)
   }
 
+  /// Emits a `PlatformEvent::MouseMove` for the current cursor position
+  /// after the given delay.
+  ///
+  /// Returns a handle that can be aborted to cancel the emission.
+  pub fn emit_mouse_move_after(
+    delay: Duration,
+  ) -> tokio::task::JoinHandle<()> {
+    EventWindow::emit_mouse_move_after(delay)
+  }
+
+  /// Creates a new `ConfigWatcher` for the specified config file.
+  pub fn start_config_watcher(
+    config_path: &Path,
+  ) -> anyhow::Result<ConfigWatcher> {
+    ConfigWatcher::start(config_path)
+  }
+
   /// Creates a new `SingleInstance`.
   pub fn new_single_instance() -> anyhow::Result<SingleInstance> {
     SingleInstance::new()
   }
 
+  /// Shows a single-character label centered over each of the given
+  /// rects. The labels are removed when the returned `WindowLabels` is
+  /// dropped.
+  pub fn show_window_labels(
+    labels: Vec<(char, Rect)>,
+  ) -> anyhow::Result<WindowLabels> {
+    WindowLabels::new(labels)
+  }
+
   // Gets the root window of the specified window.
   pub fn root_ancestor(
     window: &NativeWindow,
@@ -136,15 +161,43 @@ impl Platform {
     Ok(())
   }
 
-  /// Finds the window at the specified point in screen space.
+  /// Finds the topmost window at the specified point in screen space.
+  ///
+  /// Transparent windows (e.g. overlays that let mouse input pass through)
+  /// are skipped in favor of the first non-transparent window underneath
+  /// them.
   pub fn window_from_point(point: &Point) -> anyhow::Result<NativeWindow> {
-    let point = POINT {
-      x: point.x,
-      y: point.y,
+    let handle = unsafe {
+      WindowFromPoint(POINT {
+        x: point.x,
+        y: point.y,
+      })
     };
 
-    let handle = unsafe { WindowFromPoint(point) };
-    Ok(NativeWindow::new(handle.0))
+    let mut window =
+      NativeWindow::new(unsafe { GetAncestor(handle, GA_ROOT) }.0);
+
+    if window.is_transparent() {
+      let is_hit = |window: &NativeWindow| {
+        !window.is_transparent()
+          && window.is_visible().unwrap_or(false)
+          && window
+            .refresh_frame_position()
+            .map(|rect| rect.contains_point(point))
+            .unwrap_or(false)
+      };
+
+      // Walk down the z-order until a visible, non-transparent window
+      // that contains the point is found.
+      while window.handle != 0 && !is_hit(&window) {
+        let next_handle =
+          unsafe { GetWindow(HWND(window.handle), GW_HWNDNEXT) };
+
+        window = NativeWindow::new(next_handle.0);
+      }
+    }
+
+    Ok(window)
   }
 
   /// Gets the mouse position in screen space.
@@ -30,6 +30,9 @@ impl SingleInstance {
 
     if let Err(err) = unsafe { GetLastError() } {
       if err == ERROR_ALREADY_EXISTS.into() {
+        // Close the handle so that the mutex gets destroyed once the
+        // other instance exits.
+        let _ = unsafe { CloseHandle(handle) };
         bail!("Another instance of the application is already running.");
       }
     }
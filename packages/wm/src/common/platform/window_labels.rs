@@ -0,0 +1,240 @@
+use std::{
+  iter,
+  sync::mpsc,
+  thread::{self, JoinHandle},
+};
+
+use anyhow::bail;
+use tracing::{info, warn};
+use windows::{
+  core::{w, PCWSTR},
+  Win32::{
+    Foundation::{COLORREF, HWND, LPARAM, LRESULT, RECT, WPARAM},
+    Graphics::Gdi::{
+      BeginPaint, CreateFontW, CreateSolidBrush, DeleteObject, DrawTextW,
+      EndPaint, FillRect, SelectObject, SetBkMode, SetTextColor,
+      CLEARTYPE_QUALITY, CLIP_DEFAULT_PRECIS, DEFAULT_CHARSET,
+      DEFAULT_PITCH, DT_CENTER, DT_SINGLELINE, DT_VCENTER, FF_DONTCARE,
+      FW_BOLD, OUT_DEFAULT_PRECIS, PAINTSTRUCT, TRANSPARENT,
+    },
+    UI::WindowsAndMessaging::{
+      CreateWindowExW, DefWindowProcW, DestroyWindow, GetClientRect,
+      GetWindowTextW, RegisterClassW, SetLayeredWindowAttributes,
+      LWA_ALPHA, WM_PAINT, WNDCLASSW, WS_EX_LAYERED, WS_EX_NOACTIVATE,
+      WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_POPUP,
+      WS_VISIBLE,
+    },
+  },
+};
+
+use super::Platform;
+use crate::common::Rect;
+
+/// Width and height (in pixels) of each label.
+const LABEL_SIZE: i32 = 48;
+
+/// Background color of labels (as BGR).
+const LABEL_BACKGROUND: COLORREF = COLORREF(0x00302820);
+
+/// Text color of labels (as BGR).
+const LABEL_FOREGROUND: COLORREF = COLORREF(0x00FFFFFF);
+
+/// Click-through overlay windows that show a single-character label
+/// centered over each of the given rects.
+///
+/// The overlays are created on a separate thread with its own message
+/// loop, and are destroyed on drop.
+#[derive(Debug)]
+pub struct WindowLabels {
+  window_thread: Option<JoinHandle<anyhow::Result<()>>>,
+}
+
+impl WindowLabels {
+  /// Creates an instance of `WindowLabels`. Blocks until the overlay
+  /// windows have been created.
+  pub fn new(labels: Vec<(char, Rect)>) -> anyhow::Result<Self> {
+    let (created_tx, created_rx) = mpsc::channel();
+
+    let window_thread = thread::spawn(move || {
+      let wnd_class = WNDCLASSW {
+        lpszClassName: w!("WindowLabel"),
+        lpfnWndProc: Some(label_window_proc),
+        ..Default::default()
+      };
+
+      unsafe { RegisterClassW(&wnd_class) };
+
+      let handles = labels
+        .iter()
+        .map(|(label, rect)| Self::create_label_window(*label, rect))
+        .collect::<anyhow::Result<Vec<_>>>();
+
+      let handles = match handles {
+        Ok(handles) => {
+          let _ = created_tx.send(Ok(()));
+          handles
+        }
+        Err(err) => {
+          let _ = created_tx.send(Err(err));
+          return Ok(());
+        }
+      };
+
+      Platform::run_message_loop();
+
+      // Clean-up on message loop exit.
+      for handle in handles {
+        unsafe { DestroyWindow(handle) }?;
+      }
+
+      Ok(())
+    });
+
+    created_rx.recv()??;
+
+    Ok(Self {
+      window_thread: Some(window_thread),
+    })
+  }
+
+  fn create_label_window(
+    label: char,
+    rect: &Rect,
+  ) -> anyhow::Result<HWND> {
+    let center = rect.center_point();
+
+    let title = label
+      .to_uppercase()
+      .collect::<String>()
+      .encode_utf16()
+      .chain(iter::once(0))
+      .collect::<Vec<_>>();
+
+    let handle = unsafe {
+      CreateWindowExW(
+        WS_EX_LAYERED
+          | WS_EX_TOPMOST
+          | WS_EX_TRANSPARENT
+          | WS_EX_TOOLWINDOW
+          | WS_EX_NOACTIVATE,
+        w!("WindowLabel"),
+        PCWSTR(title.as_ptr()),
+        WS_POPUP | WS_VISIBLE,
+        center.x - LABEL_SIZE / 2,
+        center.y - LABEL_SIZE / 2,
+        LABEL_SIZE,
+        LABEL_SIZE,
+        None,
+        None,
+        None,
+        None,
+      )
+    };
+
+    if handle.0 == 0 {
+      bail!("Creation of label window failed.");
+    }
+
+    unsafe {
+      SetLayeredWindowAttributes(handle, COLORREF(0), 220, LWA_ALPHA)
+    }?;
+
+    Ok(handle)
+  }
+
+  /// Destroys the overlay windows and stops the message loop.
+  pub fn destroy(&mut self) -> anyhow::Result<()> {
+    info!("Destroying window labels.");
+
+    // Wait for the spawned thread to finish.
+    if let Some(window_thread) = self.window_thread.take() {
+      Platform::kill_message_loop(&window_thread)?;
+
+      window_thread
+        .join()
+        .map_err(|_| anyhow::anyhow!("Thread join failed."))??;
+    }
+
+    Ok(())
+  }
+}
+
+impl Drop for WindowLabels {
+  fn drop(&mut self) {
+    if let Err(err) = self.destroy() {
+      warn!("Failed to destroy window labels: {}", err);
+    }
+  }
+}
+
+/// Window procedure for the label windows.
+extern "system" fn label_window_proc(
+  handle: HWND,
+  message: u32,
+  wparam: WPARAM,
+  lparam: LPARAM,
+) -> LRESULT {
+  match message {
+    WM_PAINT => {
+      if let Err(err) = paint_label(handle) {
+        warn!("Failed to paint window label: {}", err);
+      }
+
+      LRESULT(0)
+    }
+    _ => unsafe { DefWindowProcW(handle, message, wparam, lparam) },
+  }
+}
+
+/// Draws the window's title centered over a solid background.
+fn paint_label(handle: HWND) -> anyhow::Result<()> {
+  let mut rect = RECT::default();
+  unsafe { GetClientRect(handle, &mut rect) }?;
+
+  let mut text = [0u16; 8];
+  let length = unsafe { GetWindowTextW(handle, &mut text) };
+
+  let mut paint = PAINTSTRUCT::default();
+
+  unsafe {
+    let hdc = BeginPaint(handle, &mut paint);
+
+    let brush = CreateSolidBrush(LABEL_BACKGROUND);
+    FillRect(hdc, &rect, brush);
+    DeleteObject(brush);
+
+    let font = CreateFontW(
+      LABEL_SIZE * 2 / 3,
+      0,
+      0,
+      0,
+      FW_BOLD.0 as i32,
+      0,
+      0,
+      0,
+      DEFAULT_CHARSET.0 as u32,
+      OUT_DEFAULT_PRECIS.0 as u32,
+      CLIP_DEFAULT_PRECIS.0 as u32,
+      CLEARTYPE_QUALITY.0 as u32,
+      (DEFAULT_PITCH.0 | FF_DONTCARE.0) as u32,
+      w!("Segoe UI"),
+    );
+
+    let previous_font = SelectObject(hdc, font);
+    SetBkMode(hdc, TRANSPARENT);
+    SetTextColor(hdc, LABEL_FOREGROUND);
+
+    DrawTextW(
+      hdc,
+      &mut text[..length as usize],
+      &mut rect,
+      DT_CENTER | DT_VCENTER | DT_SINGLELINE,
+    );
+
+    SelectObject(hdc, previous_font);
+    DeleteObject(font);
+    EndPaint(handle, &paint);
+  }
+
+  Ok(())
+}
@@ -1,9 +1,12 @@
 use anyhow::bail;
 
 use super::resize_tiling_container;
-use crate::containers::{
-  traits::{CommonGetters, TilingSizeGetters},
-  Container,
+use crate::{
+  containers::{
+    traits::{CommonGetters, TilingSizeGetters},
+    Container,
+  },
+  wm_state::WmState,
 };
 
 /// Inserts a child container at the specified index.
@@ -13,6 +16,7 @@ pub fn attach_container(
   child: &Container,
   target_parent: &Container,
   target_index: Option<usize>,
+  state: &mut WmState,
 ) -> anyhow::Result<()> {
   if !child.is_detached() {
     bail!("Cannot attach an already attached container.");
@@ -35,6 +39,7 @@ pub fn attach_container(
     .push_back(child.id());
 
   *child.borrow_parent_mut() = Some(target_parent.clone());
+  state.add_to_container_index(child, target_parent);
 
   // Resize the child and its siblings if it is a tiling container.
   if let Ok(child) = child.as_tiling_container() {
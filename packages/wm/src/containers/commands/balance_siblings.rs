@@ -0,0 +1,29 @@
+use anyhow::Context;
+
+use crate::{
+  containers::{
+    traits::{CommonGetters, TilingSizeGetters},
+    TilingContainer,
+  },
+  wm_state::WmState,
+};
+
+/// Resizes the tiling container and its tiling siblings to all have an
+/// equal tiling size.
+pub fn balance_siblings(
+  container: TilingContainer,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let parent = container.parent().context("No parent.")?;
+  let tiling_children = parent.tiling_children().collect::<Vec<_>>();
+
+  let tiling_size = 1.0 / tiling_children.len() as f32;
+
+  for child in &tiling_children {
+    child.set_tiling_size(tiling_size);
+  }
+
+  state.pending_sync.containers_to_redraw.push(parent);
+
+  Ok(())
+}
@@ -1,16 +1,22 @@
 use anyhow::Context;
 
 use super::flatten_split_container;
-use crate::containers::{
-  traits::{CommonGetters, TilingSizeGetters, MIN_TILING_SIZE},
-  Container,
+use crate::{
+  containers::{
+    traits::{CommonGetters, TilingSizeGetters, MIN_TILING_SIZE},
+    Container,
+  },
+  wm_state::WmState,
 };
 
 /// Removes a container from the tree.
 ///
 /// If the container is a tiling container, the siblings will be resized to
 /// fill the freed up space. Will flatten empty parent split containers.
-pub fn detach_container(child_to_remove: Container) -> anyhow::Result<()> {
+pub fn detach_container(
+  child_to_remove: Container,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
   // Flatten the parent split container if it'll be empty after removing
   // the child.
   if let Some(split_parent) = child_to_remove
@@ -18,7 +24,7 @@ pub fn detach_container(child_to_remove: Container) -> anyhow::Result<()> {
     .and_then(|parent| parent.as_split().cloned())
   {
     if split_parent.child_count() == 1 {
-      flatten_split_container(split_parent)?;
+      flatten_split_container(split_parent, state)?;
     }
   }
 
@@ -33,6 +39,7 @@ pub fn detach_container(child_to_remove: Container) -> anyhow::Result<()> {
     .retain(|id| *id != child_to_remove.id());
 
   *child_to_remove.borrow_parent_mut() = None;
+  state.remove_from_container_index(&child_to_remove);
 
   // Resize the siblings if it is a tiling container.
   if let Ok(child_to_remove) = child_to_remove.as_tiling_container() {
@@ -44,14 +51,27 @@ pub fn detach_container(child_to_remove: Container) -> anyhow::Result<()> {
         sum + container.tiling_size() - MIN_TILING_SIZE
       });
 
-    // Adjust size of the siblings based on the freed up space.
+    // Adjust size of the siblings based on the freed up space. If all
+    // siblings are at the minimum size, the space is split evenly.
     for sibling in &tiling_siblings {
-      let resize_factor =
-        (sibling.tiling_size() - MIN_TILING_SIZE) / available_size;
+      let resize_factor = match available_size > 0. {
+        true => (sibling.tiling_size() - MIN_TILING_SIZE) / available_size,
+        false => 1. / tiling_siblings.len() as f32,
+      };
 
       let size_delta = resize_factor * child_to_remove.tiling_size();
       sibling.set_tiling_size(sibling.tiling_size() + size_delta);
     }
+
+    // Normalize the sizes so that they sum to 1. Otherwise, floating point
+    // errors can accumulate after several removals.
+    let total_size = tiling_siblings
+      .iter()
+      .fold(0.0, |sum, container| sum + container.tiling_size());
+
+    for sibling in &tiling_siblings {
+      sibling.set_tiling_size(sibling.tiling_size() / total_size);
+    }
   }
 
   Ok(())
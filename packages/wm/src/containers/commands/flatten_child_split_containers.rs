@@ -1,7 +1,10 @@
 use super::flatten_split_container;
-use crate::containers::{
-  traits::{CommonGetters, TilingDirectionGetters},
-  Container,
+use crate::{
+  containers::{
+    traits::{CommonGetters, TilingDirectionGetters},
+    Container,
+  },
+  wm_state::WmState,
 };
 
 /// Flattens any redundant split containers at the top-level of the given
@@ -15,6 +18,7 @@ use crate::containers::{
 /// ```
 pub fn flatten_child_split_containers(
   parent: Container,
+  state: &mut WmState,
 ) -> anyhow::Result<()> {
   if let Ok(parent) = parent.as_direction_container() {
     // Get children that are either tiling windows or split containers.
@@ -29,7 +33,7 @@ pub fn flatten_child_split_containers(
         // Handle case where the parent is a split container and has a
         // single split container child.
         if let Some(split_child) = tiling_children[0].as_split() {
-          flatten_split_container(split_child.clone())?;
+          flatten_split_container(split_child.clone(), state)?;
           parent.set_tiling_direction(parent.tiling_direction().inverse());
         }
       }
@@ -48,11 +52,11 @@ pub fn flatten_child_split_containers(
             if let Some(split_grandchild) =
               split_child.children()[0].as_split()
             {
-              flatten_split_container(split_grandchild.clone())?;
+              flatten_split_container(split_grandchild.clone(), state)?;
             }
           }
 
-          flatten_split_container(split_child.clone())?;
+          flatten_split_container(split_child.clone(), state)?;
         }
       }
     }
@@ -0,0 +1,35 @@
+use anyhow::Context;
+
+use super::{flatten_child_split_containers, flatten_split_container};
+use crate::{
+  containers::{traits::CommonGetters, Container},
+  wm_state::WmState,
+};
+
+/// Flattens the split container of the given container (or the container
+/// itself if it's a split container), moving its children into the
+/// parent container.
+///
+/// Does nothing if the container isn't in a split container.
+pub fn flatten_container(
+  container: Container,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let split_container = match container.as_split() {
+    Some(split_container) => split_container.clone(),
+    None => match container.parent().and_then(|p| p.as_split().cloned()) {
+      Some(split_container) => split_container,
+      None => return Ok(()),
+    },
+  };
+
+  let parent = split_container.parent().context("No parent.")?;
+  flatten_split_container(split_container, state)?;
+
+  // Flatten any split containers that became redundant, e.g. a child
+  // split container with the same tiling direction.
+  flatten_child_split_containers(parent.clone(), state)?;
+  state.pending_sync.containers_to_redraw.push(parent);
+
+  Ok(())
+}
@@ -2,9 +2,12 @@ use std::collections::VecDeque;
 
 use anyhow::Context;
 
-use crate::containers::{
-  traits::{CommonGetters, TilingSizeGetters},
-  SplitContainer,
+use crate::{
+  containers::{
+    traits::{CommonGetters, TilingSizeGetters},
+    SplitContainer,
+  },
+  wm_state::WmState,
 };
 
 /// Removes a split container from the tree and moves its children
@@ -13,6 +16,7 @@ use crate::containers::{
 /// The children will be resized to fit the size of the split container.
 pub fn flatten_split_container(
   split_container: SplitContainer,
+  state: &mut WmState,
 ) -> anyhow::Result<()> {
   let parent = split_container.parent().context("No parent.")?;
 
@@ -62,6 +66,7 @@ pub fn flatten_split_container(
 
   *split_container.borrow_parent_mut() = None;
   *split_container.borrow_children_mut() = VecDeque::new();
+  state.remove_from_container_index(&split_container.into());
 
   Ok(())
 }
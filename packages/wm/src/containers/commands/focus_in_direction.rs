@@ -83,7 +83,7 @@ fn floating_focus_target(
 
 /// Gets a focus target within the current workspace. Traverse upwards from
 /// the origin container to find an adjacent container that can be focused.
-fn tiling_focus_target(
+pub fn tiling_focus_target(
   origin_container: Container,
   direction: &Direction,
 ) -> anyhow::Result<Option<Container>> {
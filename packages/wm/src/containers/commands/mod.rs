@@ -1,23 +1,31 @@
 mod attach_container;
+mod balance_siblings;
 mod detach_container;
 mod flatten_child_split_containers;
+mod flatten_container;
 mod flatten_split_container;
 mod focus_in_direction;
 mod move_container_within_tree;
 mod replace_container;
 mod resize_tiling_container;
+mod rotate_layout;
 mod set_focused_descendant;
+mod swap_containers;
 mod toggle_tiling_direction;
 mod wrap_in_split_container;
 
 pub use attach_container::*;
+pub use balance_siblings::*;
 pub use detach_container::*;
 pub use flatten_child_split_containers::*;
+pub use flatten_container::*;
 pub use flatten_split_container::*;
 pub use focus_in_direction::*;
 pub use move_container_within_tree::*;
 pub use replace_container::*;
 pub use resize_tiling_container::*;
+pub use rotate_layout::*;
 pub use set_focused_descendant::*;
+pub use swap_containers::*;
 pub use toggle_tiling_direction::*;
 pub use wrap_in_split_container::*;
@@ -28,7 +28,7 @@ pub fn move_container_within_tree(
   container_to_move: Container,
   target_parent: Container,
   target_index: usize,
-  state: &WmState,
+  state: &mut WmState,
 ) -> anyhow::Result<()> {
   // Create iterator of parent, grandparent, and great-grandparent.
   let ancestors =
@@ -98,11 +98,12 @@ pub fn move_container_within_tree(
   let is_subtree_focused =
     original_focus_index < target_parent_ancestor.focus_index();
 
-  detach_container(container_to_move.clone())?;
+  detach_container(container_to_move.clone(), state)?;
   attach_container(
     &container_to_move.clone(),
     &target_parent.clone(),
     Some(target_index),
+    state,
   )?;
 
   // Set `container_to_move` as focused descendant within target subtree if
@@ -128,7 +129,7 @@ pub fn move_container_within_tree(
   // For example, in the layout V[1 H[2]] where container 1 is moved down
   // to become V[H[1 2]], this will then need to be flattened to V[1 2].
   for ancestor in ancestors.iter().rev() {
-    flatten_child_split_containers(ancestor.clone())?;
+    flatten_child_split_containers(ancestor.clone(), state)?;
   }
 
   if container_to_move.has_focus(None) {
@@ -144,7 +145,7 @@ fn move_to_lowest_common_ancestor(
   container_to_move: Container,
   lowest_common_ancestor: Container,
   target_index: usize,
-  state: &WmState,
+  state: &mut WmState,
 ) -> anyhow::Result<()> {
   // Keep reference to focus index of container's ancestor in LCA's child
   // focus order.
@@ -156,12 +157,13 @@ fn move_to_lowest_common_ancestor(
     .map(|ancestor| ancestor.focus_index())
     .context("Failed to get focus index of container's ancestor.")?;
 
-  detach_container(container_to_move.clone())?;
+  detach_container(container_to_move.clone(), state)?;
 
   attach_container(
     &container_to_move.clone(),
     &lowest_common_ancestor.clone(),
     Some(target_index),
+    state,
   )?;
 
   lowest_common_ancestor
@@ -7,6 +7,7 @@ use crate::{
     traits::{CommonGetters, TilingSizeGetters},
     Container,
   },
+  wm_state::WmState,
 };
 
 /// Replaces a container at the specified index.
@@ -16,6 +17,7 @@ pub fn replace_container(
   replacement_container: Container,
   target_parent: Container,
   target_index: usize,
+  state: &mut WmState,
 ) -> anyhow::Result<()> {
   if !replacement_container.is_detached() {
     bail!(
@@ -38,12 +40,13 @@ pub fn replace_container(
   // container to flatten. Currently, that scenario shouldn't be possible.
   // We also can't attach first before detaching, because detaching
   // removes child based on ID and both containers might have the same ID.
-  detach_container(container_to_replace)?;
+  detach_container(container_to_replace, state)?;
 
   attach_container(
     &replacement_container,
     &target_parent,
     Some(target_index),
+    state,
   )?;
 
   // Shift to the correct focus index.
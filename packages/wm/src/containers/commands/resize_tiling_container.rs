@@ -6,6 +6,24 @@ use crate::containers::{
 pub fn resize_tiling_container(
   container_to_resize: &TilingContainer,
   target_size: f32,
+) {
+  resize_tiling_container_with_min(
+    container_to_resize,
+    target_size,
+    MIN_TILING_SIZE,
+  );
+}
+
+/// Resizes a tiling container, while keeping it and its siblings at or
+/// above the given minimum tiling size.
+///
+/// When growing, siblings are shrunk in proportion to how far they are
+/// above the minimum size. When shrinking, the freed up space is given to
+/// siblings in proportion to their current size.
+pub fn resize_tiling_container_with_min(
+  container_to_resize: &TilingContainer,
+  target_size: f32,
+  min_size: f32,
 ) {
   let tiling_siblings =
     container_to_resize.tiling_siblings().collect::<Vec<_>>();
@@ -16,29 +34,45 @@ pub fn resize_tiling_container(
     return;
   }
 
+  // Get available tiling size amongst siblings.
+  let available_size =
+    tiling_siblings.iter().fold(0.0, |sum, container| {
+      sum + container.tiling_size() - min_size
+    });
+
   // Prevent the container from being smaller than the minimum size, and
   // larger than the space available from sibling containers.
-  let clamped_target_size = target_size.clamp(
-    MIN_TILING_SIZE,
-    1. - (tiling_siblings.len() as f32 * MIN_TILING_SIZE),
-  );
+  let clamped_target_size = target_size
+    .clamp(min_size, 1. - (tiling_siblings.len() as f32 * min_size));
 
   let size_delta = clamped_target_size - container_to_resize.tiling_size();
-  container_to_resize.set_tiling_size(clamped_target_size);
 
-  // Get available tiling size amongst siblings.
-  let available_size =
-    tiling_siblings.iter().fold(0.0, |sum, container| {
-      sum + container.tiling_size() - MIN_TILING_SIZE
-    });
+  // Siblings might already be at or below the minimum size (e.g. if the
+  // minimum size is larger than when they were last resized), in which
+  // case the container can only be shrunk.
+  if size_delta > 0. && available_size <= 0. {
+    return;
+  }
+
+  let total_sibling_size = tiling_siblings
+    .iter()
+    .fold(0.0, |sum, container| sum + container.tiling_size());
+
+  container_to_resize.set_tiling_size(clamped_target_size);
 
-  // Distribute the available tiling size amongst its siblings.
+  // Distribute the size delta amongst its siblings.
   for sibling in &tiling_siblings {
-    // Get percentage of resize that affects this container. Siblings are
-    // resized in proportion to their current size (i.e. larger containers
-    // are shrunk more).
-    let resize_factor =
-      (sibling.tiling_size() - MIN_TILING_SIZE) / available_size;
+    // Get percentage of resize that affects this container. When growing,
+    // siblings are shrunk in proportion to how far they are above the
+    // minimum size. When shrinking, siblings are grown in proportion to
+    // their current size (or evenly if they have no size).
+    let resize_factor = match size_delta > 0. {
+      true => (sibling.tiling_size() - min_size) / available_size,
+      false if total_sibling_size > 0. => {
+        sibling.tiling_size() / total_sibling_size
+      }
+      false => 1. / tiling_siblings.len() as f32,
+    };
 
     let size_delta = resize_factor * size_delta;
 
@@ -0,0 +1,52 @@
+use anyhow::Context;
+
+use crate::{
+  containers::{
+    traits::{CommonGetters, TilingDirectionGetters},
+    Container,
+  },
+  wm_event::WmEvent,
+  wm_state::WmState,
+};
+
+/// Rotates the layout of the nearest direction container (i.e. split
+/// container or workspace) of the given container. The tiling direction
+/// of the direction container and all of its descendant split containers
+/// is inverted, while the order of children is preserved.
+///
+/// Rotating twice results in the original layout.
+pub fn rotate_layout(
+  container: Container,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let direction_container = match container {
+    Container::TilingWindow(_)
+    | Container::Split(_)
+    | Container::Workspace(_) => container
+      .direction_container()
+      .context("No direction container."),
+    // Can only rotate from a tiling container or workspace.
+    _ => return Ok(()),
+  }?;
+
+  let direction_containers = direction_container
+    .self_and_descendants()
+    .filter_map(|descendant| descendant.as_direction_container().ok());
+
+  for descendant in direction_containers {
+    descendant
+      .set_tiling_direction(descendant.tiling_direction().inverse());
+  }
+
+  state
+    .pending_sync
+    .containers_to_redraw
+    .extend(direction_container.tiling_children().map(Into::into));
+
+  state.emit_event(WmEvent::TilingDirectionChanged {
+    direction_container: direction_container.to_dto()?,
+    new_tiling_direction: direction_container.tiling_direction(),
+  });
+
+  Ok(())
+}
@@ -0,0 +1,69 @@
+use anyhow::{bail, Context};
+
+use super::set_focused_descendant;
+use crate::{
+  common::VecDequeExt,
+  containers::{
+    traits::{CommonGetters, TilingSizeGetters},
+    Container,
+  },
+};
+
+/// Swaps the positions of two containers in the tree.
+///
+/// Tiling sizes are swapped along with the positions, such that the
+/// layout of the tree stays the same. Focus stays with whichever of the
+/// containers was focused.
+pub fn swap_containers(
+  container_a: &Container,
+  container_b: &Container,
+) -> anyhow::Result<()> {
+  if container_a.self_and_ancestors().any(|c| &c == container_b)
+    || container_b.self_and_ancestors().any(|c| &c == container_a)
+  {
+    bail!("Cannot swap a container with its own ancestor.");
+  }
+
+  let parent_a = container_a.parent().context("No parent.")?;
+  let parent_b = container_b.parent().context("No parent.")?;
+  let index_a = container_a.index();
+  let index_b = container_b.index();
+
+  let focused_container = [container_a, container_b]
+    .into_iter()
+    .find(|container| container.has_focus(None))
+    .cloned();
+
+  parent_a.borrow_children_mut()[index_a] = container_b.clone();
+  parent_b.borrow_children_mut()[index_b] = container_a.clone();
+
+  // Focus order only needs to be updated if the containers are moving
+  // between different parents.
+  if parent_a != parent_b {
+    parent_a
+      .borrow_child_focus_order_mut()
+      .replace(&container_a.id(), container_b.id());
+
+    parent_b
+      .borrow_child_focus_order_mut()
+      .replace(&container_b.id(), container_a.id());
+
+    *container_a.borrow_parent_mut() = Some(parent_b);
+    *container_b.borrow_parent_mut() = Some(parent_a);
+  }
+
+  if let (Ok(tiling_a), Ok(tiling_b)) = (
+    container_a.as_tiling_container(),
+    container_b.as_tiling_container(),
+  ) {
+    let tiling_size_a = tiling_a.tiling_size();
+    tiling_a.set_tiling_size(tiling_b.tiling_size());
+    tiling_b.set_tiling_size(tiling_size_a);
+  }
+
+  if let Some(focused_container) = focused_container {
+    set_focused_descendant(focused_container, None);
+  }
+
+  Ok(())
+}
@@ -19,7 +19,7 @@ pub fn toggle_tiling_direction(
 ) -> anyhow::Result<()> {
   let direction_container = match container {
     Container::TilingWindow(tiling_window) => {
-      toggle_window_direction(tiling_window, config)
+      toggle_window_direction(tiling_window, state, config)
     }
     Container::Workspace(workspace) => {
       workspace
@@ -41,6 +41,7 @@ pub fn toggle_tiling_direction(
 
 fn toggle_window_direction(
   tiling_window: TilingWindow,
+  state: &mut WmState,
   config: &UserConfig,
 ) -> anyhow::Result<DirectionContainer> {
   let parent = tiling_window
@@ -59,7 +60,7 @@ fn toggle_window_direction(
         Ok(workspace.into())
       }
       DirectionContainer::Split(split_container) => {
-        flatten_split_container(split_container.clone())?;
+        flatten_split_container(split_container.clone(), state)?;
 
         tiling_window
           .direction_container()
@@ -78,6 +79,7 @@ fn toggle_window_direction(
     split_container.clone(),
     parent.into(),
     vec![tiling_window.clone().into()],
+    state,
   )?;
 
   Ok(split_container.into())
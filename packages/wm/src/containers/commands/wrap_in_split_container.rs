@@ -2,15 +2,19 @@ use std::collections::VecDeque;
 
 use anyhow::Context;
 
-use crate::containers::{
-  traits::{CommonGetters, TilingSizeGetters},
-  Container, SplitContainer, TilingContainer,
+use crate::{
+  containers::{
+    traits::{CommonGetters, TilingSizeGetters},
+    Container, SplitContainer, TilingContainer,
+  },
+  wm_state::WmState,
 };
 
 pub fn wrap_in_split_container(
   split_container: SplitContainer,
   target_parent: Container,
   target_children: Vec<TilingContainer>,
+  state: &mut WmState,
 ) -> anyhow::Result<()> {
   let starting_index = target_children
     .iter()
@@ -79,5 +83,7 @@ pub fn wrap_in_split_container(
   // Add original focus order to split container.
   *split_container.borrow_child_focus_order_mut() = sorted_focus_ids;
 
+  state.add_to_container_index(&split_container.into(), &target_parent);
+
   Ok(())
 }
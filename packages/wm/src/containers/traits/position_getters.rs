@@ -17,6 +17,13 @@ macro_rules! impl_position_getters_as_resizable {
   ($struct_name:ident) => {
     impl PositionGetters for $struct_name {
       fn to_rect(&self) -> anyhow::Result<Rect> {
+        // Tiling containers fill the whole workspace in monocle mode.
+        if let Some(workspace) =
+          self.workspace().filter(|workspace| workspace.is_monocle())
+        {
+          return workspace.to_rect();
+        }
+
         let parent = self
           .parent()
           .and_then(|parent| parent.as_direction_container().ok())
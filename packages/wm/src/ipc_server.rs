@@ -1,4 +1,4 @@
-use std::{iter, net::SocketAddr};
+use std::{collections::HashMap, iter, net::SocketAddr};
 
 use anyhow::{bail, Context};
 use clap::Parser;
@@ -21,8 +21,9 @@ use crate::{
     ContainerDto,
   },
   user_config::{BindingModeConfig, UserConfig},
+  windows::traits::WindowGetters,
   wm::WindowManager,
-  wm_event::WmEvent,
+  wm_event::{WmEvent, WmEventLogEntry},
 };
 
 pub const DEFAULT_IPC_PORT: u32 = 6123;
@@ -49,6 +50,7 @@ pub enum ClientResponseData {
   AppMetadata(AppMetadataData),
   BindingModes(BindingModesData),
   Command(CommandData),
+  EventLog(EventLogData),
   EventSubscribe(EventSubscribeData),
   EventUnsubscribe,
   Focused(FocusedData),
@@ -76,6 +78,12 @@ pub struct CommandData {
   pub subject_container_id: Uuid,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLogData {
+  pub events: Vec<WmEventLogEntry>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EventSubscribeData {
@@ -105,6 +113,11 @@ pub struct TilingDirectionData {
 #[serde(rename_all = "camelCase")]
 pub struct WindowsData {
   pub windows: Vec<ContainerDto>,
+
+  /// PNG-encoded icons by window ID. Only included if requested, and
+  /// windows whose icon can't be retrieved are omitted.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub icons: Option<HashMap<Uuid, Vec<u8>>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -261,14 +274,24 @@ impl IpcServer {
   ) -> anyhow::Result<ClientResponseData> {
     let response_data = match app_command {
       AppCommand::Query { command } => match command {
-        QueryCommand::Windows => {
+        QueryCommand::Windows { include_icons } => {
+          let windows = wm.state.windows();
+
+          let icons = include_icons.then(|| {
+            windows
+              .iter()
+              .filter_map(|window| {
+                window.native().icon().ok().map(|icon| (window.id(), icon))
+              })
+              .collect()
+          });
+
           ClientResponseData::Windows(WindowsData {
-            windows: wm
-              .state
-              .windows()
+            windows: windows
               .into_iter()
               .map(|window| window.to_dto())
               .try_collect()?,
+            icons,
           })
         }
         QueryCommand::Workspaces => {
@@ -296,6 +319,11 @@ impl IpcServer {
             binding_modes: wm.state.binding_modes.clone(),
           })
         }
+        QueryCommand::EventLog => {
+          ClientResponseData::EventLog(EventLogData {
+            events: wm.state.event_log(),
+          })
+        }
         QueryCommand::Focused => {
           let focused_container = wm
             .state
@@ -8,6 +8,7 @@ pub mod containers;
 pub mod ipc_client;
 pub mod ipc_server;
 pub mod monitors;
+pub mod session;
 pub mod sys_tray;
 pub mod user_config;
 pub mod windows;
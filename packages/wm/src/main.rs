@@ -5,9 +5,14 @@
 #![feature(iterator_try_collect)]
 #![feature(once_cell_try)]
 
-use std::{env, path::PathBuf};
+use std::{
+  env,
+  path::PathBuf,
+  time::{Duration, Instant},
+};
 
-use anyhow::{Context, Error, Result};
+use anyhow::{bail, Context, Error, Result};
+use clap::Parser;
 use tokio::{process::Command, signal};
 use tracing::{debug, error, info, warn, Level};
 use tracing_subscriber::{
@@ -17,9 +22,10 @@ use tracing_subscriber::{
 
 use crate::{
   app_command::{AppCommand, InvokeCommand, Verbosity},
-  common::platform::Platform,
+  common::platform::{ConfigWatcher, Platform, SingleInstance},
   ipc_client::IpcClient,
   ipc_server::{ClientResponseData, IpcServer},
+  session::Session,
   sys_tray::SystemTray,
   user_config::UserConfig,
   wm::WindowManager,
@@ -33,6 +39,7 @@ mod containers;
 mod ipc_client;
 mod ipc_server;
 mod monitors;
+mod session;
 mod sys_tray;
 mod user_config;
 mod windows;
@@ -41,6 +48,10 @@ mod wm_event;
 mod wm_state;
 mod workspaces;
 
+/// Max time to wait for an existing instance to exit when starting with
+/// `--replace`.
+const REPLACE_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Main entry point for the application.
 ///
 /// Conditionally starts the WM or runs a CLI command based on the given
@@ -54,8 +65,16 @@ async fn main() -> Result<()> {
     AppCommand::Start {
       config_path,
       verbosity,
+      debug_events,
+      dry_run,
+      replace,
     } => {
-      let res = start_wm(config_path, verbosity).await;
+      if dry_run {
+        return validate_config(config_path);
+      }
+
+      let res =
+        start_wm(config_path, verbosity, debug_events, replace).await;
 
       // If unable to start the WM, the error is fatal and a message dialog
       // is shown.
@@ -70,9 +89,29 @@ async fn main() -> Result<()> {
   }
 }
 
+/// Parses and validates the user config without starting the WM.
+///
+/// Uses the same code path as on startup, so that any errors (e.g.
+/// invalid window rules or commands) are caught in the same way. Unlike
+/// on startup, a missing config file is an error rather than being
+/// created from the sample config.
+fn validate_config(config_path: Option<PathBuf>) -> Result<()> {
+  let config_path = UserConfig::path_or_default(config_path)?;
+
+  if !config_path.exists() {
+    bail!("No config file found at {}.", config_path.display());
+  }
+
+  let config = UserConfig::new(Some(config_path))?;
+  println!("Config at {} is valid.", config.path.display());
+  Ok(())
+}
+
 async fn start_wm(
   config_path: Option<PathBuf>,
   verbosity: Verbosity,
+  debug_events: bool,
+  replace: bool,
 ) -> Result<()> {
   let error_log_dir = home::home_dir()
     .context("Unable to get home directory.")?
@@ -100,12 +139,17 @@ async fn start_wm(
     verbosity.level().to_string()
   );
 
-  // Ensure that only one instance of the WM is running.
-  let _single_instance = Platform::new_single_instance()?;
-
-  // Parse and validate user config.
+  // Parse and validate user config. This is done before replacing an
+  // existing instance, so that an invalid config doesn't leave the user
+  // without a running WM.
   let mut config = UserConfig::new(config_path)?;
 
+  // Ensure that only one instance of the WM is running.
+  let _single_instance = match replace {
+    true => replace_existing_instance().await?,
+    false => Platform::new_single_instance()?,
+  };
+
   // Start watcher process for restoring hidden windows on crash.
   start_watcher_process()?;
 
@@ -113,16 +157,24 @@ async fn start_wm(
   let mut tray = SystemTray::new(&config.path)?;
 
   let mut wm = WindowManager::new(&mut config)?;
+  tray.update_workspaces(&wm.state, &config);
 
   let mut ipc_server = IpcServer::start().await?;
 
   // Start listening for platform events after populating initial state.
   let mut event_listener = Platform::start_event_listener(&config)?;
 
+  // Watch the config file for changes to automatically reload it.
+  let mut config_watcher = start_config_watcher(&config);
+
   // Run startup commands.
   let startup_commands = config.value.general.startup_commands.clone();
   wm.process_commands(startup_commands, None, &mut config)?;
 
+  // Listen for the console window getting closed, so that cleanup can be
+  // run before the process is terminated.
+  let mut ctrl_close = signal::windows::ctrl_close()?;
+
   loop {
     let res = tokio::select! {
       Some(_) = tray.exit_rx.recv() => {
@@ -137,6 +189,10 @@ async fn start_wm(
         info!("Received SIGINT signal.");
         break;
       },
+      Some(_) = ctrl_close.recv() => {
+        info!("Received console close signal.");
+        break;
+      },
       Some(event) = event_listener.event_rx.recv() => {
         debug!("Received platform event: {:?}", event);
         wm.process_event(event, &mut config)
@@ -159,6 +215,13 @@ async fn start_wm(
       Some(wm_event) = wm.event_rx.recv() => {
         info!("Received WM event: {:?}", wm_event);
 
+        if debug_events {
+          match serde_json::to_string(&wm_event) {
+            Ok(json) => eprintln!("{}", json),
+            Err(err) => warn!("Failed to serialize WM event: {}", err),
+          }
+        }
+
         // Update event listener when keyboard or mouse listener needs to
         // be changed.
         if matches!(
@@ -172,8 +235,65 @@ async fn start_wm(
           );
         }
 
+        if let WmEvent::UserConfigChanged { .. } = wm_event {
+          // Clear any previous config error shown in the system tray.
+          tray.set_config_error(None);
+
+          // Start or stop watching the config file if the option has
+          // been toggled.
+          if config.value.general.reload_config_on_change
+            != config_watcher.is_some()
+          {
+            config_watcher = start_config_watcher(&config);
+          }
+        }
+
+        // Update the workspaces shown in the system tray menu.
+        if matches!(
+          wm_event,
+          WmEvent::FocusChanged { .. }
+            | WmEvent::UserConfigChanged { .. }
+            | WmEvent::WorkspaceActivated { .. }
+            | WmEvent::WorkspaceDeactivated { .. }
+            | WmEvent::WorkspaceUpdated { .. }
+        ) {
+          tray.update_workspaces(&wm.state, &config);
+        }
+
         ipc_server.process_event(wm_event)
       },
+      Some(_) = async {
+        match config_watcher.as_mut() {
+          Some(config_watcher) => config_watcher.change_rx.recv().await,
+          None => std::future::pending().await,
+        }
+      } => {
+        // Report errors in the system tray instead of a dialog, since
+        // the config is likely to be invalid while it's being edited.
+        if let Err(err) = wm.process_commands(
+          vec![InvokeCommand::WmReloadConfig],
+          None,
+          &mut config,
+        ) {
+          warn!("Failed to reload config on change: {:?}", err);
+          tray.set_config_error(Some(err.to_string()));
+        }
+
+        Ok(())
+      },
+      Some(workspace_name) = tray.focus_workspace_rx.recv() => {
+        InvokeCommand::try_parse_from([
+          "",
+          "focus",
+          "--workspace",
+          &workspace_name,
+        ])
+        .map_err(Into::into)
+        .and_then(|command| {
+          wm.process_commands(vec![command], None, &mut config)
+        })
+        .map(|_| ())
+      },
       Some(_) = tray.config_reload_rx.recv() => {
         wm.process_commands(
           vec![InvokeCommand::WmReloadConfig],
@@ -198,9 +318,67 @@ async fn start_wm(
     );
   }
 
+  // Save the layout of managed windows to restore on next startup.
+  if config.value.general.restore_session {
+    if let Err(err) = Session::from_state(&wm.state).write() {
+      warn!("Failed to save session: {:?}", err);
+    }
+  }
+
   Ok(())
 }
 
+/// Exits an already running instance of the WM (if any), and waits for
+/// it to release its single instance lock.
+///
+/// The existing instance is exited through IPC, so that it runs its
+/// usual cleanup (e.g. showing hidden windows) before shutting down.
+async fn replace_existing_instance() -> Result<SingleInstance> {
+  if let Ok(mut client) = IpcClient::connect().await {
+    info!("Exiting existing WM instance.");
+
+    let message = "command wm-exit";
+    client
+      .send(message)
+      .await
+      .context("Failed to send exit command to existing instance.")?;
+
+    // The connection might get closed before a response is received.
+    let _ = client.client_response(message).await;
+  }
+
+  let start = Instant::now();
+
+  loop {
+    match Platform::new_single_instance() {
+      Ok(single_instance) => return Ok(single_instance),
+      Err(err) if start.elapsed() > REPLACE_TIMEOUT => {
+        return Err(err.context("Existing instance failed to exit."));
+      }
+      Err(_) => tokio::time::sleep(Duration::from_millis(100)).await,
+    }
+  }
+}
+
+/// Starts watching the config file for changes if
+/// `general.reload_config_on_change` is enabled.
+///
+/// Failing to start the watcher (e.g. for a config on a network share)
+/// is not fatal, since the config can still be reloaded manually.
+fn start_config_watcher(config: &UserConfig) -> Option<ConfigWatcher> {
+  if !config.value.general.reload_config_on_change {
+    return None;
+  }
+
+  match Platform::start_config_watcher(&config.path) {
+    Ok(config_watcher) => Some(config_watcher),
+    Err(err) => {
+      warn!("Failed to watch config file for changes: {:?}", err);
+      None
+    }
+  }
+}
+
 async fn start_cli(args: Vec<String>) -> Result<()> {
   let mut client = IpcClient::connect().await?;
 
@@ -30,6 +30,7 @@ pub fn add_monitor(
     &monitor.clone().into(),
     &state.root_container.clone().into(),
     None,
+    state,
   )?;
 
   state.emit_event(WmEvent::MonitorAdded {
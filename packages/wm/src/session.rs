@@ -0,0 +1,516 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  common::{platform::NativeWindow, TilingDirection},
+  containers::{
+    commands::{
+      attach_container, detach_container, flatten_child_split_containers,
+    },
+    traits::{CommonGetters, TilingDirectionGetters, TilingSizeGetters},
+    Container, SplitContainer, TilingContainer,
+  },
+  user_config::UserConfig,
+  windows::{traits::WindowGetters, TilingWindow},
+  wm_state::WmState,
+};
+
+/// Layout of managed windows that is saved on shutdown and restored on
+/// the next startup.
+///
+/// Windows are matched by their process name and class name, since window
+/// handles don't persist across restarts.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+  workspaces: Vec<SessionWorkspace>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SessionWorkspace {
+  name: String,
+  tiling_direction: TilingDirection,
+
+  /// Tiling tree of the workspace.
+  children: Vec<SessionContainer>,
+
+  /// Windows on the workspace that aren't tiling (e.g. floating windows).
+  non_tiling_windows: Vec<SessionWindow>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum SessionContainer {
+  #[serde(rename_all = "camelCase")]
+  Split {
+    tiling_direction: TilingDirection,
+    tiling_size: f32,
+    children: Vec<SessionContainer>,
+  },
+  #[serde(rename_all = "camelCase")]
+  Window {
+    tiling_size: f32,
+    window: SessionWindow,
+  },
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SessionWindow {
+  process_name: String,
+  class_name: String,
+
+  /// Window that has been matched to this entry on startup.
+  #[serde(skip)]
+  restored_window: Option<NativeWindow>,
+}
+
+impl Session {
+  /// Creates a session from the currently managed windows.
+  pub fn from_state(state: &WmState) -> Self {
+    let workspaces = state
+      .workspaces()
+      .into_iter()
+      .map(|workspace| {
+        let non_tiling_windows = workspace
+          .descendants()
+          .filter_map(|descendant| {
+            let window = descendant.as_non_tiling_window()?;
+            SessionWindow::from_native(&window.native())
+          })
+          .collect();
+
+        SessionWorkspace {
+          name: workspace.config().name,
+          tiling_direction: workspace.tiling_direction(),
+          children: Self::session_containers(&workspace.clone().into()),
+          non_tiling_windows,
+        }
+      })
+      .collect();
+
+    Self { workspaces }
+  }
+
+  /// Gets the tiling tree of the given container.
+  fn session_containers(parent: &Container) -> Vec<SessionContainer> {
+    parent
+      .tiling_children()
+      .filter_map(|child| match child {
+        TilingContainer::Split(split) => Some(SessionContainer::Split {
+          tiling_direction: split.tiling_direction(),
+          tiling_size: split.tiling_size(),
+          children: Self::session_containers(&split.clone().into()),
+        }),
+        TilingContainer::TilingWindow(window) => {
+          Some(SessionContainer::Window {
+            tiling_size: window.tiling_size(),
+            window: SessionWindow::from_native(&window.native())?,
+          })
+        }
+      })
+      .collect()
+  }
+
+  /// Reads the session saved by a previous run of the WM.
+  ///
+  /// Returns an empty session if no session has been saved.
+  pub fn read() -> anyhow::Result<Self> {
+    let session_path = Self::path()?;
+
+    if !session_path.exists() {
+      return Ok(Self::default());
+    }
+
+    let session_str = fs::read_to_string(&session_path)
+      .context("Unable to read session file.")?;
+
+    serde_json::from_str(&session_str)
+      .context("Unable to parse session file.")
+  }
+
+  /// Writes the session to disk, overwriting any previous session.
+  pub fn write(&self) -> anyhow::Result<()> {
+    let session_path = Self::path()?;
+    let parent_dir =
+      session_path.parent().context("Invalid session path.")?;
+
+    fs::create_dir_all(parent_dir).with_context(|| {
+      format!("Unable to create directory {}.", parent_dir.display())
+    })?;
+
+    fs::write(&session_path, serde_json::to_string(self)?).with_context(
+      || format!("Unable to write to {}.", session_path.display()),
+    )
+  }
+
+  /// Gets the name of the workspace that a window was on in the saved
+  /// session.
+  ///
+  /// The window is matched to the first unmatched entry, so that multiple
+  /// windows of the same application are restored to their respective
+  /// workspaces and positions.
+  pub fn take_workspace_name(
+    &mut self,
+    native_window: &NativeWindow,
+  ) -> Option<String> {
+    let process_name = native_window.process_name().ok()?;
+    let class_name = native_window.class_name().ok()?;
+
+    let session_window =
+      self.workspaces.iter_mut().find_map(|workspace| {
+        let session_window = Self::find_window(
+          &mut workspace.children,
+          &process_name,
+          &class_name,
+        )
+        .or_else(|| {
+          workspace
+            .non_tiling_windows
+            .iter_mut()
+            .find(|window| window.is_unmatched(&process_name, &class_name))
+        })?;
+
+        Some((session_window, workspace.name.clone()))
+      });
+
+    let (session_window, workspace_name) = session_window?;
+    session_window.restored_window = Some(native_window.clone());
+
+    Some(workspace_name)
+  }
+
+  /// Finds the first unmatched window entry in the given tiling tree.
+  fn find_window<'a>(
+    containers: &'a mut [SessionContainer],
+    process_name: &str,
+    class_name: &str,
+  ) -> Option<&'a mut SessionWindow> {
+    containers.iter_mut().find_map(|container| match container {
+      SessionContainer::Split { children, .. } => {
+        Self::find_window(children, process_name, class_name)
+      }
+      SessionContainer::Window { window, .. } => window
+        .is_unmatched(process_name, class_name)
+        .then_some(window),
+    })
+  }
+
+  /// Moves the restored tiling windows to their saved position in the
+  /// tiling tree of their workspace.
+  ///
+  /// Saved windows that are no longer open are skipped, and any split
+  /// containers that would become redundant are left out. Windows on the
+  /// workspace that weren't in the session are kept after the restored
+  /// windows.
+  pub fn restore_layouts(
+    &self,
+    state: &mut WmState,
+    config: &UserConfig,
+  ) -> anyhow::Result<()> {
+    for session_workspace in &self.workspaces {
+      let workspace =
+        match state.workspace_by_name(&session_workspace.name) {
+          Some(workspace) => workspace,
+          None => continue,
+        };
+
+      // Get the restored windows that are still tiling on the workspace.
+      // Window rules might have since moved them elsewhere.
+      let mut restored_windows = HashMap::new();
+      Self::collect_restored_windows(
+        &session_workspace.children,
+        &mut restored_windows,
+        state,
+      );
+
+      restored_windows.retain(|_, window| {
+        window.workspace().map(|workspace| workspace.id())
+          == Some(workspace.id())
+      });
+
+      if restored_windows.is_empty() {
+        continue;
+      }
+
+      // Detach the windows so that they can be re-attached in their
+      // saved position.
+      for window in restored_windows.values() {
+        detach_container(window.clone().into(), state)?;
+      }
+
+      // Flatten any split containers that became redundant after
+      // detaching, starting with the most deeply nested ones. For example,
+      // V[H[1]] where window 1 wasn't in the session becomes V[1].
+      let direction_containers = workspace
+        .self_and_descendants()
+        .filter(|container| {
+          container.is_workspace() || container.is_split()
+        })
+        .collect::<Vec<_>>();
+
+      for container in direction_containers.into_iter().rev() {
+        if !container.is_detached() {
+          flatten_child_split_containers(container, state)?;
+        }
+      }
+
+      workspace
+        .set_tiling_direction(session_workspace.tiling_direction.clone());
+
+      let mut sized_children = Vec::new();
+      Self::restore_children(
+        &workspace.clone().into(),
+        &session_workspace.children,
+        1.0,
+        &mut sized_children,
+        &restored_windows,
+        state,
+        config,
+      )?;
+
+      Self::set_tiling_sizes(&workspace.clone().into(), &sized_children);
+
+      state
+        .pending_sync
+        .containers_to_redraw
+        .push(workspace.into());
+    }
+
+    Ok(())
+  }
+
+  /// Gets the managed tiling windows that have been matched to an entry
+  /// in the given tiling tree, by their handle.
+  fn collect_restored_windows(
+    containers: &[SessionContainer],
+    restored_windows: &mut HashMap<isize, TilingWindow>,
+    state: &WmState,
+  ) {
+    for container in containers {
+      match container {
+        SessionContainer::Split { children, .. } => {
+          Self::collect_restored_windows(
+            children,
+            restored_windows,
+            state,
+          );
+        }
+        SessionContainer::Window { window, .. } => {
+          let tiling_window = window
+            .restored_window
+            .as_ref()
+            .and_then(|native| state.window_from_native(native))
+            .and_then(|window| window.as_tiling_window().cloned());
+
+          if let Some(tiling_window) = tiling_window {
+            let handle = tiling_window.native().handle;
+            restored_windows.insert(handle, tiling_window);
+          }
+        }
+      }
+    }
+  }
+
+  /// Attaches the restored windows of the given tiling tree to the
+  /// parent, after any previously restored children of the parent.
+  ///
+  /// The attached containers are added to `sized_children` along with
+  /// their saved tiling size multiplied by `scale`.
+  fn restore_children(
+    parent: &Container,
+    containers: &[SessionContainer],
+    scale: f32,
+    sized_children: &mut Vec<(TilingContainer, f32)>,
+    restored_windows: &HashMap<isize, TilingWindow>,
+    state: &mut WmState,
+    config: &UserConfig,
+  ) -> anyhow::Result<()> {
+    let parent_direction =
+      parent.as_direction_container()?.tiling_direction();
+
+    for container in containers {
+      match container {
+        SessionContainer::Window {
+          tiling_size,
+          window,
+        } => {
+          let window = window
+            .restored_window
+            .as_ref()
+            .and_then(|native| restored_windows.get(&native.handle));
+
+          if let Some(window) = window {
+            attach_container(
+              &window.clone().into(),
+              parent,
+              Some(sized_children.len()),
+              state,
+            )?;
+
+            sized_children
+              .push((window.clone().into(), tiling_size * scale));
+          }
+        }
+        SessionContainer::Split {
+          tiling_direction,
+          tiling_size,
+          children,
+        } => {
+          let restored_children = children
+            .iter()
+            .filter(|child| child.has_restored_window(restored_windows))
+            .collect::<Vec<_>>();
+
+          match restored_children.as_slice() {
+            [] => {}
+            // Splice the children into the parent if the split container
+            // would be redundant. A single remaining child takes up the
+            // split container's full size.
+            [child] => Self::restore_children(
+              parent,
+              children,
+              scale * tiling_size / child.tiling_size().max(f32::EPSILON),
+              sized_children,
+              restored_windows,
+              state,
+              config,
+            )?,
+            _ if *tiling_direction == parent_direction => {
+              Self::restore_children(
+                parent,
+                children,
+                scale * tiling_size,
+                sized_children,
+                restored_windows,
+                state,
+                config,
+              )?
+            }
+            _ => {
+              let split = SplitContainer::new(
+                tiling_direction.clone(),
+                config.value.gaps.inner_gap.clone(),
+              );
+
+              attach_container(
+                &split.clone().into(),
+                parent,
+                Some(sized_children.len()),
+                state,
+              )?;
+
+              sized_children
+                .push((split.clone().into(), tiling_size * scale));
+
+              let mut split_sized_children = Vec::new();
+              Self::restore_children(
+                &split.clone().into(),
+                children,
+                1.0,
+                &mut split_sized_children,
+                restored_windows,
+                state,
+                config,
+              )?;
+
+              Self::set_tiling_sizes(&split.into(), &split_sized_children);
+            }
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Sets the tiling sizes of the parent's children proportionally to
+  /// their saved sizes.
+  ///
+  /// Children without a saved size (i.e. windows that weren't in the
+  /// session) get an even share.
+  fn set_tiling_sizes(
+    parent: &Container,
+    sized_children: &[(TilingContainer, f32)],
+  ) {
+    let tiling_children = parent.tiling_children().collect::<Vec<_>>();
+    let even_size = 1.0 / tiling_children.len() as f32;
+
+    let saved_share = sized_children.len() as f32 * even_size;
+    let total_saved_size = sized_children
+      .iter()
+      .fold(0.0, |sum, (_, tiling_size)| sum + tiling_size);
+
+    for child in &tiling_children {
+      let saved_size = sized_children
+        .iter()
+        .find(|(sized_child, _)| sized_child.id() == child.id())
+        .map(|(_, tiling_size)| *tiling_size);
+
+      let tiling_size = match saved_size {
+        Some(saved_size) if total_saved_size > 0. => {
+          saved_size / total_saved_size * saved_share
+        }
+        _ => even_size,
+      };
+
+      child.set_tiling_size(tiling_size);
+    }
+  }
+
+  /// Gets the path to the session file at
+  /// `%APPDATA%/glazewm/session.json`.
+  fn path() -> anyhow::Result<PathBuf> {
+    let app_data_dir = std::env::var_os("APPDATA")
+      .context("Unable to get app data path.")?;
+
+    Ok(PathBuf::from(app_data_dir).join("glazewm/session.json"))
+  }
+}
+
+impl SessionContainer {
+  fn tiling_size(&self) -> f32 {
+    match self {
+      SessionContainer::Split { tiling_size, .. } => *tiling_size,
+      SessionContainer::Window { tiling_size, .. } => *tiling_size,
+    }
+  }
+
+  /// Whether the container is or contains a restored window.
+  fn has_restored_window(
+    &self,
+    restored_windows: &HashMap<isize, TilingWindow>,
+  ) -> bool {
+    match self {
+      SessionContainer::Split { children, .. } => children
+        .iter()
+        .any(|child| child.has_restored_window(restored_windows)),
+      SessionContainer::Window { window, .. } => {
+        window.restored_window.as_ref().is_some_and(|native| {
+          restored_windows.contains_key(&native.handle)
+        })
+      }
+    }
+  }
+}
+
+impl SessionWindow {
+  fn from_native(native_window: &NativeWindow) -> Option<Self> {
+    Some(Self {
+      process_name: native_window.process_name().ok()?,
+      class_name: native_window.class_name().ok()?,
+      restored_window: None,
+    })
+  }
+
+  /// Whether the entry matches the given window and hasn't already been
+  /// matched to another window.
+  fn is_unmatched(&self, process_name: &str, class_name: &str) -> bool {
+    self.restored_window.is_none()
+      && self.process_name == process_name
+      && self.class_name == class_name
+  }
+}
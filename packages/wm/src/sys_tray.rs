@@ -4,11 +4,16 @@ use anyhow::Context;
 use tokio::sync::mpsc;
 use tracing::{info, warn};
 use tray_icon::{
-  menu::{CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem},
+  menu::{
+    CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu,
+  },
   Icon, TrayIconBuilder,
 };
 
-use crate::common::platform::Platform;
+use crate::{
+  common::platform::Platform, containers::traits::CommonGetters,
+  user_config::UserConfig, wm_state::WmState,
+};
 
 /// Ordinal to IDI_ICON definition in embedded resource file.
 const IDI_ICON: u16 = 32512;
@@ -16,13 +21,33 @@ const IDI_ICON: u16 = 32512;
 pub struct SystemTray {
   pub config_reload_rx: mpsc::UnboundedReceiver<()>,
   pub exit_rx: mpsc::UnboundedReceiver<()>,
+
+  /// Receiver for the names of workspaces selected in the tray menu.
+  pub focus_workspace_rx: mpsc::UnboundedReceiver<String>,
+
+  workspaces_tx: mpsc::UnboundedSender<Vec<TrayWorkspace>>,
+  config_error_tx: mpsc::UnboundedSender<Option<String>>,
   icon_thread: Option<JoinHandle<anyhow::Result<()>>>,
 }
 
+/// Workspace entry in the tray menu.
+#[derive(Clone, Debug, PartialEq)]
+struct TrayWorkspace {
+  name: String,
+  label: String,
+  is_focused: bool,
+}
+
 impl SystemTray {
   pub fn new(config_path: &PathBuf) -> anyhow::Result<Self> {
     let (exit_tx, exit_rx) = mpsc::unbounded_channel();
     let (config_reload_tx, config_reload_rx) = mpsc::unbounded_channel();
+    let (focus_workspace_tx, focus_workspace_rx) =
+      mpsc::unbounded_channel();
+    let (workspaces_tx, mut workspaces_rx) =
+      mpsc::unbounded_channel::<Vec<TrayWorkspace>>();
+    let (config_error_tx, mut config_error_rx) =
+      mpsc::unbounded_channel::<Option<String>>();
     let config_dir = config_path
       .parent()
       .context("Invalid config path.")?
@@ -44,6 +69,11 @@ impl SystemTray {
         None,
       );
 
+      // Items are added once the active workspaces are received.
+      let workspaces_menu = Submenu::new("Workspaces", true);
+      let mut workspaces: Vec<TrayWorkspace> = Vec::new();
+      let mut workspace_items: Vec<CheckMenuItem> = Vec::new();
+
       let exit_item = MenuItem::new("Exit", true, None);
 
       let tray_menu = Menu::new();
@@ -52,20 +82,81 @@ impl SystemTray {
         &config_dir_item,
         &animations_item,
         &PredefinedMenuItem::separator(),
+        &workspaces_menu,
+        &PredefinedMenuItem::separator(),
         &exit_item,
       ])?;
 
+      let tooltip = format!("GlazeWM v{}", env!("VERSION_NUMBER"));
+
       let icon = Icon::from_resource(IDI_ICON, None)?;
-      let _tray_icon = TrayIconBuilder::new()
+      let tray_icon = TrayIconBuilder::new()
         .with_menu(Box::new(tray_menu))
-        .with_tooltip(format!("GlazeWM v{}", env!("VERSION_NUMBER")))
+        .with_tooltip(&tooltip)
         .with_icon(icon)
         .build()?;
 
       let menu_event_rx = MenuEvent::receiver();
 
       loop {
+        // Rebuild the workspace items from the latest received workspaces.
+        let mut new_workspaces = None;
+        while let Ok(received) = workspaces_rx.try_recv() {
+          new_workspaces = Some(received);
+        }
+
+        if let Some(new_workspaces) =
+          new_workspaces.filter(|new| *new != workspaces)
+        {
+          for item in workspace_items.drain(..) {
+            workspaces_menu.remove(&item)?;
+          }
+
+          for workspace in &new_workspaces {
+            let item = CheckMenuItem::new(
+              &workspace.label,
+              true,
+              workspace.is_focused,
+              None,
+            );
+
+            workspaces_menu.append(&item)?;
+            workspace_items.push(item);
+          }
+
+          workspaces = new_workspaces;
+        }
+
+        // Show the latest config error (if any) in the tooltip.
+        let mut new_config_error = None;
+        while let Ok(received) = config_error_rx.try_recv() {
+          new_config_error = Some(received);
+        }
+
+        if let Some(config_error) = new_config_error {
+          let new_tooltip = match config_error {
+            Some(err) => format!("{}\nConfig error: {}", tooltip, err),
+            None => tooltip.clone(),
+          };
+
+          // Tooltips are limited to 128 characters (including the null
+          // terminator).
+          let new_tooltip = match new_tooltip.chars().count() > 127 {
+            true => {
+              let truncated = new_tooltip.chars().take(124);
+              format!("{}...", truncated.collect::<String>())
+            }
+            false => new_tooltip,
+          };
+
+          tray_icon.set_tooltip(Some(new_tooltip))?;
+        }
+
         if let Ok(event) = menu_event_rx.try_recv() {
+          let workspace_index = workspace_items
+            .iter()
+            .position(|item| event.id == *item.id());
+
           if event.id == reload_config_item.id() {
             config_reload_tx.send(())?;
           } else if event.id == config_dir_item.id() {
@@ -77,6 +168,18 @@ impl SystemTray {
               Platform::set_window_animations_enabled(!animations_enabled);
 
             animations_enabled = !animations_enabled;
+          } else if let Some(index) = workspace_index {
+            if !workspaces[index].is_focused {
+              focus_workspace_tx.send(workspaces[index].name.clone())?;
+            }
+
+            // Clicking toggles the checkmark, so restore it until the
+            // updated workspaces are received.
+            for (item, workspace) in
+              workspace_items.iter().zip(&workspaces)
+            {
+              item.set_checked(workspace.is_focused);
+            }
           } else if event.id == exit_item.id() {
             exit_tx.send(())?;
           }
@@ -96,10 +199,48 @@ impl SystemTray {
     Ok(Self {
       config_reload_rx,
       exit_rx,
+      focus_workspace_rx,
+      workspaces_tx,
+      config_error_tx,
       icon_thread: Some(icon_thread),
     })
   }
 
+  /// Updates the workspaces shown in the tray menu, with a checkmark next
+  /// to the focused workspace.
+  pub fn update_workspaces(&self, state: &WmState, config: &UserConfig) {
+    let focused_workspace_id = state
+      .focused_container()
+      .and_then(|focused| focused.workspace())
+      .map(|workspace| workspace.id());
+
+    let workspaces = state
+      .sorted_workspaces(config)
+      .into_iter()
+      .map(|workspace| {
+        let config = workspace.config();
+
+        TrayWorkspace {
+          label: config.display_name.unwrap_or(config.name.clone()),
+          name: config.name,
+          is_focused: Some(workspace.id()) == focused_workspace_id,
+        }
+      })
+      .collect();
+
+    if let Err(err) = self.workspaces_tx.send(workspaces) {
+      warn!("Failed to update workspaces in system tray: {}", err);
+    }
+  }
+
+  /// Shows the given config error in the tray icon's tooltip, or clears
+  /// it if `None`.
+  pub fn set_config_error(&self, error: Option<String>) {
+    if let Err(err) = self.config_error_tx.send(error) {
+      warn!("Failed to update config error in system tray: {}", err);
+    }
+  }
+
   /// Destroys the system tray icon and stops its associated message loop.
   pub fn destroy(&mut self) -> anyhow::Result<()> {
     info!("Shutting down system tray.");
@@ -1,11 +1,11 @@
 use std::{collections::HashMap, fs, path::PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
 
 use crate::{
   app_command::InvokeCommand,
-  common::{Color, LengthValue, RectDelta},
+  common::{platform::KeyboardHook, Color, LengthValue, RectDelta},
   containers::{traits::CommonGetters, WindowContainer},
   monitors::Monitor,
   windows::traits::WindowGetters,
@@ -38,11 +38,7 @@ impl UserConfig {
   ///
   /// Creates a new config file from sample if it doesn't exist.
   pub fn new(config_path: Option<PathBuf>) -> anyhow::Result<Self> {
-    let default_config_path = home::home_dir()
-      .context("Unable to get home directory.")?
-      .join(".glzr/glazewm/config.yaml");
-
-    let config_path = config_path.unwrap_or(default_config_path);
+    let config_path = Self::path_or_default(config_path)?;
     let (config_value, config_str) = Self::read(&config_path)?;
 
     let window_rules_by_event = Self::window_rules_by_event(&config_value);
@@ -55,6 +51,21 @@ impl UserConfig {
     })
   }
 
+  /// Gets the given config path, or the default path at
+  /// `%userprofile%/.glzr/glazewm/config.yaml` if none is given.
+  pub fn path_or_default(
+    config_path: Option<PathBuf>,
+  ) -> anyhow::Result<PathBuf> {
+    match config_path {
+      Some(config_path) => Ok(config_path),
+      None => Ok(
+        home::home_dir()
+          .context("Unable to get home directory.")?
+          .join(".glzr/glazewm/config.yaml"),
+      ),
+    }
+  }
+
   /// Reads and validates the user config from the given path.
   ///
   /// Creates a new config file from sample if it doesn't exist.
@@ -71,10 +82,90 @@ impl UserConfig {
     // TODO: Improve error formatting of serde_yaml errors. Something
     // similar to https://github.com/AlexanderThaller/format_serde_error
     let config_value = serde_yaml::from_str(&config_str)?;
+    Self::validate(&config_value)?;
 
     Ok((config_value, config_str))
   }
 
+  /// Checks the parsed config for invalid values that can't be caught
+  /// when deserializing.
+  ///
+  /// All errors are collected and returned together.
+  fn validate(config_value: &ParsedConfig) -> anyhow::Result<()> {
+    let mut errors = Vec::new();
+
+    // Check for duplicate workspace names.
+    for (index, workspace) in config_value.workspaces.iter().enumerate() {
+      let is_duplicate = config_value.workspaces[..index]
+        .iter()
+        .any(|other| other.name == workspace.name);
+
+      if is_duplicate {
+        errors.push(format!(
+          "Workspace name '{}' is used more than once.",
+          workspace.name
+        ));
+      }
+    }
+
+    // Check for keys that aren't recognized on the current keyboard.
+    let keybindings = config_value.keybindings.iter().chain(
+      config_value
+        .binding_modes
+        .iter()
+        .flat_map(|mode| mode.keybindings.iter()),
+    );
+
+    for binding in keybindings.flat_map(|keybinding| &keybinding.bindings)
+    {
+      let invalid_keys = binding
+        .split("+")
+        .filter(|key| KeyboardHook::key_to_vk_code(key).is_none())
+        .collect::<Vec<_>>();
+
+      if !invalid_keys.is_empty() {
+        errors.push(format!(
+          "Keybinding '{}' has unrecognized keys: {}.",
+          binding,
+          invalid_keys.join(", ")
+        ));
+      }
+    }
+
+    // Check that opacity values are valid percentages.
+    let window_effects = [
+      (
+        "focused_window",
+        &config_value.window_effects.focused_window,
+      ),
+      ("other_windows", &config_value.window_effects.other_windows),
+    ];
+
+    for (name, window_effect) in window_effects {
+      if window_effect.transparency.opacity > 100 {
+        errors.push(format!(
+          "Opacity of 'window_effects.{}' must be between 0 and 100.",
+          name
+        ));
+      }
+    }
+
+    for (index, rule) in config_value.window_rules.iter().enumerate() {
+      if rule.opacity.is_some_and(|opacity| opacity > 100) {
+        errors.push(format!(
+          "Opacity of window rule {} must be between 0 and 100.",
+          index + 1
+        ));
+      }
+    }
+
+    if !errors.is_empty() {
+      bail!("Invalid config:\n{}", errors.join("\n"));
+    }
+
+    Ok(())
+  }
+
   /// Initializes a new config file from the sample config resource.
   fn create_sample(config_path: PathBuf) -> Result<()> {
     let parent_dir =
@@ -132,6 +223,9 @@ impl UserConfig {
           ..WindowMatchConfig::default()
         },
       ],
+      float_size: None,
+      ignore_exclusion_zones: None,
+      opacity: None,
       on: vec![WindowRuleEvent::Manage],
       run_once: true,
     });
@@ -180,6 +274,9 @@ impl UserConfig {
           ..WindowMatchConfig::default()
         },
       ],
+      float_size: None,
+      ignore_exclusion_zones: None,
+      opacity: None,
       on: vec![WindowRuleEvent::Manage],
       run_once: true,
     });
@@ -221,6 +318,8 @@ impl UserConfig {
     let window_title = window.native().title()?;
     let window_class = window.native().class_name()?;
     let window_process = window.native().process_name()?;
+    let workspace_name =
+      window.workspace().map(|workspace| workspace.config().name);
 
     let pending_window_rules = self
       .window_rules_by_event
@@ -253,7 +352,16 @@ impl UserConfig {
             .map(|match_type| match_type.is_match(&window_title))
             .unwrap_or(true);
 
-          is_process_match && is_class_match && is_title_match
+          let is_workspace_match = match_config
+            .workspace
+            .as_ref()
+            .map(|name| workspace_name.as_ref() == Some(name))
+            .unwrap_or(true);
+
+          is_process_match
+            && is_class_match
+            && is_title_match
+            && is_workspace_match
         })
       })
       .cloned()
@@ -324,11 +432,27 @@ impl UserConfig {
   }
 
   pub fn sort_workspaces(&self, workspaces: &mut Vec<Workspace>) {
+    // Workspaces that aren't defined in the config (i.e. dynamic
+    // workspaces) are sorted last.
     workspaces.sort_by_key(|workspace| {
-      self.workspace_config_index(&workspace.config().name)
+      self
+        .workspace_config_index(&workspace.config().name)
+        .unwrap_or(usize::MAX)
     });
   }
 
+  /// Gets the outer gap of a workspace. Falls back to the global outer gap
+  /// if the workspace doesn't override it.
+  pub fn outer_gap(
+    &self,
+    workspace_config: &WorkspaceConfig,
+  ) -> RectDelta {
+    workspace_config
+      .outer_gap
+      .clone()
+      .unwrap_or_else(|| self.value.gaps.outer_gap.clone())
+  }
+
   pub fn has_outer_gaps(&self) -> bool {
     let outer_gap = &self.value.gaps.outer_gap;
 
@@ -386,11 +510,38 @@ pub struct GeneralConfig {
   #[serde(default = "default_bool::<false>")]
   pub focus_follows_cursor: bool,
 
+  /// Minimum time (in milliseconds) that the cursor needs to stay over a
+  /// window before it gets focused by `focus_follows_cursor`.
+  #[serde(default)]
+  pub focus_follows_cursor_delay_ms: u32,
+
+  /// Whether to create workspaces on demand when a workspace that isn't
+  /// defined in `workspaces` is focused or moved to.
+  #[serde(default = "default_bool::<false>")]
+  pub dynamic_workspaces: bool,
+
+  /// Whether to save the layout of each workspace on shutdown, and move
+  /// matching windows back to their workspace and position in the tiling
+  /// tree on the next startup. The session is saved to
+  /// `%APPDATA%/glazewm/session.json`.
+  #[serde(default = "default_bool::<false>")]
+  pub restore_session: bool,
+
+  /// Whether to automatically reload the config when the config file is
+  /// changed.
+  #[serde(default = "default_bool::<false>")]
+  pub reload_config_on_change: bool,
+
   /// Whether to switch back and forth between the previously focused
   /// workspace when focusing the current workspace.
   #[serde(default = "default_bool::<true>")]
   pub toggle_workspace_on_refocus: bool,
 
+  /// Max number of previously displayed workspaces to remember per
+  /// monitor for the `switch-to-previous-workspace` command.
+  #[serde(default = "default_workspace_history_depth")]
+  pub workspace_history_depth: usize,
+
   /// Commands to run when the WM has started (e.g. to run a script or
   /// launch another application).
   #[serde(default)]
@@ -438,6 +589,25 @@ pub struct WindowBehaviorConfig {
   /// changes the defaults for when the state change commands, like
   /// `set_floating`, are used without any flags.
   pub state_defaults: WindowStateDefaultsConfig,
+
+  /// Minimum width (in pixels) that tiling windows can be resized to.
+  #[serde(default = "default_min_tile_length")]
+  pub min_tile_width: u32,
+
+  /// Minimum height (in pixels) that tiling windows can be resized to.
+  #[serde(default = "default_min_tile_length")]
+  pub min_tile_height: u32,
+
+  /// Whether to remove the title bar and borders of tiling windows. These
+  /// are restored when the window stops tiling or is unmanaged.
+  #[serde(default = "default_bool::<false>")]
+  pub remove_tiling_title_bars: bool,
+
+  /// Direction to split the focused tiling window in when a new tiling
+  /// window is added. If not set, new windows are added after the
+  /// focused window in its parent's tiling direction.
+  #[serde(default)]
+  pub new_window_split_direction: Option<NewWindowSplitDirection>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
@@ -448,6 +618,17 @@ pub enum InitialWindowState {
   Floating,
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NewWindowSplitDirection {
+  /// Splits along the longer side of the focused window.
+  Auto,
+  Right,
+  Bottom,
+  Left,
+  Top,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all(serialize = "camelCase"))]
 pub struct WindowStateDefaultsConfig {
@@ -477,6 +658,11 @@ pub struct FullscreenStateConfig {
   /// Whether to show fullscreen windows as always on top.
   #[serde(default = "default_bool::<false>")]
   pub shown_on_top: bool,
+
+  /// Whether to remove the title bar and borders of fullscreen windows.
+  /// These are restored when the window exits fullscreen.
+  #[serde(default = "default_bool::<false>")]
+  pub remove_title_bar: bool,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -494,6 +680,10 @@ pub struct WindowEffectsConfig {
 pub struct WindowEffectConfig {
   /// Config for optionally applying a colored border.
   pub border: BorderEffectConfig,
+
+  /// Config for optionally applying transparency.
+  #[serde(default)]
+  pub transparency: TransparencyEffectConfig,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -508,11 +698,43 @@ pub struct BorderEffectConfig {
   pub color: Color,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all(serialize = "camelCase"))]
+pub struct TransparencyEffectConfig {
+  /// Whether to enable the effect.
+  #[serde(default = "default_bool::<false>")]
+  pub enabled: bool,
+
+  /// Opacity of the window as a percentage (0-100).
+  #[serde(default = "default_opacity")]
+  pub opacity: u8,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all(serialize = "camelCase"))]
 pub struct WindowRuleConfig {
+  #[serde(default)]
   pub commands: Vec<InvokeCommand>,
 
+  /// Size to use for the window when it's floating. The window is
+  /// centered on its workspace.
+  ///
+  /// Only applied when the window is managed, or when the rule's
+  /// commands first make the window floating.
+  #[serde(default)]
+  pub float_size: Option<FloatSizeConfig>,
+
+  /// Whether the window can be placed over the reserved areas of a
+  /// monitor (e.g. the taskbar) while floating.
+  #[serde(default)]
+  pub ignore_exclusion_zones: Option<bool>,
+
+  /// Opacity of the window as a percentage (0-100). Takes precedence over
+  /// the transparency window effect. Only applied when the window is
+  /// managed.
+  #[serde(default)]
+  pub opacity: Option<u8>,
+
   #[serde(rename = "match")]
   pub match_window: Vec<WindowMatchConfig>,
 
@@ -523,6 +745,13 @@ pub struct WindowRuleConfig {
   pub run_once: bool,
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all(serialize = "camelCase"))]
+pub struct FloatSizeConfig {
+  pub width: u32,
+  pub height: u32,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all(serialize = "camelCase"))]
 pub struct WindowMatchConfig {
@@ -534,6 +763,10 @@ pub struct WindowMatchConfig {
 
   #[serde(default)]
   pub window_title: Option<MatchType>,
+
+  /// Name of the workspace that the window must be on.
+  #[serde(default)]
+  pub workspace: Option<String>,
 }
 
 /// Due to limitations in `serde_yaml`, we need to use an untagged enum
@@ -579,6 +812,9 @@ pub struct WorkspaceConfig {
   pub bind_to_monitor: Option<u32>,
   #[serde(default = "default_bool::<false>")]
   pub keep_alive: bool,
+  /// Overrides the global outer gap for this workspace.
+  #[serde(default)]
+  pub outer_gap: Option<RectDelta>,
 }
 
 /// Helper function for setting a default value for a boolean field.
@@ -586,6 +822,23 @@ const fn default_bool<const V: bool>() -> bool {
   V
 }
 
+/// Helper function for setting a default value for a minimum tile size
+/// field.
+const fn default_min_tile_length() -> u32 {
+  50
+}
+
+/// Helper function for setting a default value for the workspace history
+/// depth field.
+const fn default_workspace_history_depth() -> usize {
+  10
+}
+
+/// Helper function for setting a default value for an opacity field.
+const fn default_opacity() -> u8 {
+  100
+}
+
 /// Helper function for setting a default value for a color field.
 const fn default_blue() -> Color {
   Color {
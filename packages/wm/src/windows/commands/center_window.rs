@@ -0,0 +1,53 @@
+use anyhow::Context;
+
+use super::update_window_state;
+use crate::{
+  containers::{
+    traits::{CommonGetters, PositionGetters},
+    WindowContainer,
+  },
+  user_config::{FloatingStateConfig, UserConfig},
+  windows::{traits::WindowGetters, WindowState},
+  wm_state::WmState,
+};
+
+/// Centers a window within its workspace.
+///
+/// Tiling windows are changed to floating before being centered. Windows
+/// in other states (e.g. fullscreen or minimized) are left as-is.
+pub fn center_window(
+  window: WindowContainer,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let window = match window.state() {
+    WindowState::Tiling => {
+      let floating_defaults =
+        &config.value.window_behavior.state_defaults.floating;
+
+      update_window_state(
+        window,
+        WindowState::Floating(FloatingStateConfig {
+          centered: true,
+          shown_on_top: floating_defaults.shown_on_top,
+        }),
+        state,
+        config,
+      )?
+    }
+    WindowState::Floating(_) => window,
+    _ => return Ok(()),
+  };
+
+  let workspace = window.workspace().context("No workspace.")?;
+
+  window.set_floating_placement(
+    window
+      .floating_placement()
+      .translate_to_center(&workspace.to_rect()?),
+  );
+
+  state.pending_sync.containers_to_redraw.push(window.into());
+
+  Ok(())
+}
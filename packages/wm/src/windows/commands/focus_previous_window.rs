@@ -0,0 +1,50 @@
+use anyhow::Context;
+use tracing::info;
+
+use crate::{
+  containers::{commands::set_focused_descendant, traits::CommonGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+  workspaces::{commands::focus_workspace, WorkspaceTarget},
+};
+
+/// Focuses the most recently focused window other than the currently
+/// focused one. The window's workspace is displayed if it isn't already.
+pub fn focus_previous_window(
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let focused_container =
+    state.focused_container().context("No focused container.")?;
+
+  // Windows in the history might have since been unmanaged.
+  let window = state
+    .window_history
+    .iter()
+    .rev()
+    .filter(|id| **id != focused_container.id())
+    .find_map(|id| state.container_by_id(*id))
+    .and_then(|container| container.as_window_container().ok());
+
+  let window = match window {
+    Some(window) => window,
+    None => return Ok(()),
+  };
+
+  info!("Focusing previous window.");
+
+  let workspace = window.workspace().context("No workspace.")?;
+
+  if !workspace.is_displayed() {
+    focus_workspace(
+      WorkspaceTarget::Name(workspace.config().name),
+      state,
+      config,
+    )?;
+  }
+
+  set_focused_descendant(window.into(), None);
+  state.pending_sync.focus_change = true;
+
+  Ok(())
+}
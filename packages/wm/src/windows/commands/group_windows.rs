@@ -0,0 +1,76 @@
+use anyhow::{bail, Context};
+
+use crate::{
+  containers::{
+    commands::{move_container_within_tree, wrap_in_split_container},
+    traits::{CommonGetters, TilingDirectionGetters},
+    SplitContainer,
+  },
+  user_config::UserConfig,
+  windows::TilingWindow,
+  wm_state::WmState,
+};
+
+/// Groups the given tiling windows into a new split container, which is
+/// placed where the first window was.
+///
+/// The split container uses the inverse tiling direction of the first
+/// window's parent. All windows need to be on the same workspace.
+pub fn group_windows(
+  windows: Vec<TilingWindow>,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let first_window = match windows.as_slice() {
+    [first_window, _, ..] => first_window.clone(),
+    _ => bail!("At least two windows are needed to create a group."),
+  };
+
+  let workspace = first_window.workspace().context("No workspace.")?;
+
+  if windows.iter().any(|window| {
+    window.workspace().map(|workspace| workspace.id())
+      != Some(workspace.id())
+  }) {
+    bail!("Grouped windows need to be on the same workspace.");
+  }
+
+  if workspace.is_locked() {
+    bail!(
+      "Cannot group windows on locked workspace: '{}'.",
+      workspace.config().name
+    );
+  }
+
+  let parent = first_window
+    .direction_container()
+    .context("No direction container.")?;
+
+  let split_container = SplitContainer::new(
+    parent.tiling_direction().inverse(),
+    config.value.gaps.inner_gap.clone(),
+  );
+
+  wrap_in_split_container(
+    split_container.clone(),
+    parent.into(),
+    vec![first_window.clone().into()],
+    state,
+  )?;
+
+  for window in windows.into_iter().skip(1) {
+    move_container_within_tree(
+      window.into(),
+      split_container.clone().into(),
+      split_container.child_count(),
+      state,
+    )?;
+  }
+
+  state
+    .pending_sync
+    .containers_to_redraw
+    .push(workspace.into());
+
+  Ok(())
+}
@@ -18,13 +18,13 @@ pub fn ignore_window(
   let ancestors = window.ancestors().take(3).collect::<Vec<_>>();
 
   state.ignored_windows.push(window.native().clone());
-  detach_container(window.clone().into())?;
+  detach_container(window.clone().into(), state)?;
 
   // After detaching the container, flatten any redundant split containers.
   // For example, in the layout V[1 H[2]] where container 1 is detached to
   // become V[H[2]], this will then need to be flattened to V[2].
   for ancestor in ancestors.iter().rev() {
-    flatten_child_split_containers(ancestor.clone())?;
+    flatten_child_split_containers(ancestor.clone(), state)?;
   }
 
   // Sibling containers need to be redrawn if the window was tiling.
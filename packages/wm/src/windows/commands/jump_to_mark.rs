@@ -0,0 +1,46 @@
+use anyhow::Context;
+use tracing::{info, warn};
+
+use crate::{
+  containers::{commands::set_focused_descendant, traits::CommonGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+  workspaces::{commands::focus_workspace, WorkspaceTarget},
+};
+
+/// Focuses the window with the given mark. The window's workspace is
+/// displayed if it isn't already.
+pub fn jump_to_mark(
+  label: char,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let window = match state
+    .window_marks
+    .get(&label)
+    .and_then(|native_window| state.window_from_native(native_window))
+  {
+    Some(window) => window,
+    None => {
+      warn!("No window with mark: '{}'.", label);
+      return Ok(());
+    }
+  };
+
+  info!("Jumping to mark: '{}'.", label);
+
+  let workspace = window.workspace().context("No workspace.")?;
+
+  if !workspace.is_displayed() {
+    focus_workspace(
+      WorkspaceTarget::Name(workspace.config().name),
+      state,
+      config,
+    )?;
+  }
+
+  set_focused_descendant(window.into(), None);
+  state.pending_sync.focus_change = true;
+
+  Ok(())
+}
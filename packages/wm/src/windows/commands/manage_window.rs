@@ -2,15 +2,20 @@ use anyhow::Context;
 use tracing::info;
 
 use crate::{
-  common::{platform::NativeWindow, LengthValue, RectDelta},
+  common::{
+    platform::NativeWindow, Direction, LengthValue, RectDelta,
+    TilingDirection,
+  },
   containers::{
-    commands::{attach_container, set_focused_descendant},
-    traits::{CommonGetters, PositionGetters},
-    Container, WindowContainer,
+    commands::{
+      attach_container, set_focused_descendant, wrap_in_split_container,
+    },
+    traits::{CommonGetters, PositionGetters, TilingDirectionGetters},
+    Container, SplitContainer, WindowContainer,
   },
   monitors::Monitor,
   try_warn,
-  user_config::{UserConfig, WindowRuleEvent},
+  user_config::{NewWindowSplitDirection, UserConfig, WindowRuleEvent},
   windows::{
     commands::run_window_rules, traits::WindowGetters, NonTilingWindow,
     TilingWindow, WindowState,
@@ -73,20 +78,52 @@ fn create_window(
   state: &mut WmState,
   config: &UserConfig,
 ) -> anyhow::Result<WindowContainer> {
+  let nearest_monitor = state
+    .nearest_monitor(&native_window)
+    .context("No nearest monitor.")?;
+
+  let window_state =
+    window_state_to_create(&native_window, &nearest_monitor, config)?;
+
+  // Float new windows on locked workspaces to keep the layout as-is.
+  let is_locked = target_parent
+    .clone()
+    .or_else(|| state.focused_container())
+    .and_then(|container| container.workspace())
+    .is_some_and(|workspace| workspace.is_locked());
+
+  let window_state = match window_state {
+    WindowState::Tiling if is_locked => WindowState::Floating(
+      config.value.window_behavior.state_defaults.floating.clone(),
+    ),
+    _ => window_state,
+  };
+
+  // Get the owner window if it's managed (e.g. the main window of a
+  // dialog).
+  let owner_window = native_window
+    .owner()
+    .and_then(|owner| state.window_from_native(&owner));
+
   // Attach the new window as the first child of the target parent (if
-  // provided), otherwise, add as a sibling of the focused container.
-  let (target_parent, target_index) = match target_parent {
-    Some(parent) => (parent, 0),
-    None => insertion_target(state)?,
+  // provided). Dialogs are added to the workspace of their owner, and
+  // otherwise, the window is added as a sibling of the focused container.
+  let (target_parent, target_index) = match (target_parent, &owner_window)
+  {
+    (Some(parent), _) => (parent, 0),
+    (None, Some(owner_window)) => {
+      let workspace = owner_window.workspace().context("No workspace.")?;
+      let child_count = workspace.child_count();
+      (workspace.into(), child_count)
+    }
+    (None, None) => {
+      insertion_target(window_state == WindowState::Tiling, state, config)?
+    }
   };
 
   let target_workspace =
     target_parent.workspace().context("No target workspace.")?;
 
-  let nearest_monitor = state
-    .nearest_monitor(&native_window)
-    .context("No nearest monitor.")?;
-
   let nearest_workspace = nearest_monitor
     .displayed_workspace()
     .context("No nearest workspace.")?;
@@ -100,13 +137,17 @@ fn create_window(
 
   // Calculate where window should be placed when floating is enabled. Use
   // the original width/height of the window and optionally position it in
-  // the center of the workspace.
+  // the center of the workspace. Dialogs are instead centered over their
+  // owner.
   let is_same_workspace = nearest_workspace.id() == target_workspace.id();
-  let floating_placement = match !is_same_workspace || prefers_centered {
-    true => native_window
+  let floating_placement = match &owner_window {
+    Some(owner_window) => native_window
+      .frame_position()?
+      .translate_to_center(&owner_window.to_rect()?),
+    None if !is_same_workspace || prefers_centered => native_window
       .frame_position()?
       .translate_to_center(&target_workspace.to_rect()?),
-    false => native_window.frame_position()?,
+    None => native_window.frame_position()?,
   }
   // Clamp the window size to 90% of the workspace size.
   .clamp_size(
@@ -123,9 +164,11 @@ fn create_window(
     LengthValue::from_px(0),
   );
 
+  // Cache the process creation time while the process is still alive.
+  // This is used to tell apart windows that reuse the same handle.
+  let _ = native_window.process_creation_time();
+
   let inner_gap = config.value.gaps.inner_gap.clone();
-  let window_state =
-    window_state_to_create(&native_window, &nearest_monitor, config)?;
 
   let window_container: WindowContainer = match window_state {
     WindowState::Tiling => TilingWindow::new(
@@ -133,6 +176,9 @@ fn create_window(
       native_window,
       None,
       border_delta,
+      None,
+      None,
+      false,
       floating_placement,
       inner_gap,
       Vec::new(),
@@ -146,6 +192,9 @@ fn create_window(
       None,
       border_delta,
       None,
+      None,
+      false,
+      None,
       floating_placement,
       Vec::new(),
       None,
@@ -157,8 +206,18 @@ fn create_window(
     &window_container.clone().into(),
     &target_parent,
     Some(target_index),
+    state,
   )?;
 
+  // Link dialogs to their owner, so that they get unmanaged together.
+  if let Some(owner_window) =
+    owner_window.filter(|_| window_container.native().is_dialog())
+  {
+    state
+      .dialog_owners
+      .insert(window_container.id(), owner_window.id());
+  }
+
   // The OS might spawn the window on a different monitor to the target
   // parent, so adjustments might need to be made because of DPI.
   if nearest_monitor
@@ -199,8 +258,8 @@ fn window_state_to_create(
     ));
   }
 
-  // Initialize windows that can't be resized as floating.
-  if !native_window.is_resizable() {
+  // Initialize dialogs and windows that can't be resized as floating.
+  if native_window.is_dialog() || !native_window.is_resizable() {
     return Ok(WindowState::Floating(
       config.value.window_behavior.state_defaults.floating.clone(),
     ));
@@ -209,17 +268,111 @@ fn window_state_to_create(
   Ok(WindowState::default_from_config(config))
 }
 
+/// Gets the parent and index to insert a new window at, based on the
+/// focused container.
+///
+/// New tiling windows split the focused tiling window if a
+/// `new_window_split_direction` is configured.
 fn insertion_target(
-  state: &WmState,
+  is_tiling: bool,
+  state: &mut WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<(Container, usize)> {
   let focused_container =
     state.focused_container().context("No focused container.")?;
 
-  match focused_container.is_workspace() {
-    true => Ok((focused_container, 0)),
-    false => Ok((
+  if focused_container.is_workspace() {
+    return Ok((focused_container, 0));
+  }
+
+  let split_direction = config
+    .value
+    .window_behavior
+    .new_window_split_direction
+    .as_ref();
+
+  match (split_direction, focused_container.as_tiling_window()) {
+    (Some(split_direction), Some(focused_window)) if is_tiling => {
+      split_insertion_target(
+        focused_window,
+        split_direction,
+        state,
+        config,
+      )
+    }
+    _ => Ok((
       focused_container.parent().context("No insertion target.")?,
       focused_container.index() + 1,
     )),
   }
 }
+
+/// Gets the insertion target for splitting the given tiling window in
+/// the given direction.
+///
+/// The window is wrapped in a split container if its parent's tiling
+/// direction doesn't match the split direction.
+fn split_insertion_target(
+  window: &TilingWindow,
+  split_direction: &NewWindowSplitDirection,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<(Container, usize)> {
+  let direction = match split_direction {
+    NewWindowSplitDirection::Auto => {
+      let rect = window.to_rect()?;
+
+      match rect.width() >= rect.height() {
+        true => Direction::Right,
+        false => Direction::Down,
+      }
+    }
+    NewWindowSplitDirection::Right => Direction::Right,
+    NewWindowSplitDirection::Bottom => Direction::Down,
+    NewWindowSplitDirection::Left => Direction::Left,
+    NewWindowSplitDirection::Top => Direction::Up,
+  };
+
+  let tiling_direction = TilingDirection::from_direction(&direction);
+  let parent = window
+    .direction_container()
+    .context("No direction container.")?;
+
+  if parent.tiling_direction() != tiling_direction {
+    // Change the tiling direction of the parent if the window is an only
+    // child. Otherwise, wrap the window in a new split container.
+    let direction_container = match window.tiling_siblings().count() {
+      0 => {
+        parent.set_tiling_direction(tiling_direction.clone());
+        parent
+      }
+      _ => {
+        let split_container = SplitContainer::new(
+          tiling_direction.clone(),
+          config.value.gaps.inner_gap.clone(),
+        );
+
+        wrap_in_split_container(
+          split_container.clone(),
+          parent.into(),
+          vec![window.clone().into()],
+          state,
+        )?;
+
+        split_container.into()
+      }
+    };
+
+    state.emit_event(WmEvent::TilingDirectionChanged {
+      direction_container: direction_container.to_dto()?,
+      new_tiling_direction: tiling_direction,
+    });
+  }
+
+  let target_index = match direction {
+    Direction::Right | Direction::Down => window.index() + 1,
+    _ => window.index(),
+  };
+
+  Ok((window.parent().context("No parent.")?, target_index))
+}
@@ -0,0 +1,28 @@
+use anyhow::bail;
+use tracing::info;
+
+use crate::{
+  containers::WindowContainer, windows::traits::WindowGetters,
+  wm_state::WmState,
+};
+
+/// Assigns a mark to the window, which can later be used to focus the
+/// window via `jump_to_mark`. Marks are single lowercase letters (a-z).
+///
+/// If the mark is already assigned to a different window, it's moved to
+/// this window.
+pub fn mark_window(
+  window: WindowContainer,
+  label: char,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  if !label.is_ascii_lowercase() {
+    bail!("Mark must be a lowercase letter (a-z), got '{}'.", label);
+  }
+
+  info!("Marking window with '{}'.", label);
+
+  state.window_marks.insert(label, window.native().clone());
+
+  Ok(())
+}
@@ -1,19 +1,45 @@
+mod center_window;
+mod focus_previous_window;
+mod group_windows;
 mod ignore_window;
+mod jump_to_mark;
 mod manage_window;
+mod mark_window;
+mod move_window_by;
 mod move_window_in_direction;
+mod move_window_to_monitor;
 mod move_window_to_workspace;
+mod rename_window;
 mod resize_window;
 mod run_window_rules;
+mod set_scratchpad;
 mod set_window_size;
+mod swap_window_in_direction;
+mod toggle_scratchpad;
+mod unignore_window;
 mod unmanage_window;
 mod update_window_state;
+mod window_select_mode;
 
+pub use center_window::*;
+pub use focus_previous_window::*;
+pub use group_windows::*;
 pub use ignore_window::*;
+pub use jump_to_mark::*;
 pub use manage_window::*;
+pub use mark_window::*;
+pub use move_window_by::*;
 pub use move_window_in_direction::*;
+pub use move_window_to_monitor::*;
 pub use move_window_to_workspace::*;
+pub use rename_window::*;
 pub use resize_window::*;
 pub use run_window_rules::*;
+pub use set_scratchpad::*;
 pub use set_window_size::*;
+pub use swap_window_in_direction::*;
+pub use toggle_scratchpad::*;
+pub use unignore_window::*;
 pub use unmanage_window::*;
 pub use update_window_state::*;
+pub use window_select_mode::*;
@@ -0,0 +1,52 @@
+use anyhow::Context;
+
+use crate::{
+  common::{Direction, LengthValue},
+  containers::{traits::CommonGetters, WindowContainer},
+  windows::{traits::WindowGetters, WindowState},
+  wm_state::WmState,
+};
+
+/// Moves a floating window by a given distance in the given direction.
+///
+/// Percentage distances are relative to the monitor's width or height.
+/// The window is kept within the bounds of its monitor. Windows that
+/// aren't floating are left as-is.
+pub fn move_window_by(
+  window: WindowContainer,
+  direction: &Direction,
+  distance: &LengthValue,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  if !matches!(window.state(), WindowState::Floating(_)) {
+    return Ok(());
+  }
+
+  let monitor = window.monitor().context("No monitor.")?;
+  let monitor_rect = window.floating_bounds(&monitor)?;
+  let window_rect = window.floating_placement();
+
+  let monitor_length = match direction {
+    Direction::Up | Direction::Down => monitor_rect.height(),
+    _ => monitor_rect.width(),
+  };
+
+  let moved_rect = window_rect
+    .translate_in_direction(direction, distance.to_px(monitor_length));
+
+  // Prevent the window from being moved off-screen.
+  let x = moved_rect
+    .x()
+    .min(monitor_rect.right - moved_rect.width())
+    .max(monitor_rect.left);
+
+  let y = moved_rect
+    .y()
+    .min(monitor_rect.bottom - moved_rect.height())
+    .max(monitor_rect.top);
+
+  window.set_floating_placement(moved_rect.translate_to_coordinates(x, y));
+  state.pending_sync.containers_to_redraw.push(window.into());
+
+  Ok(())
+}
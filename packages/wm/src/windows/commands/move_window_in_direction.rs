@@ -1,4 +1,5 @@
 use anyhow::Context;
+use tracing::warn;
 
 use crate::{
   common::{Direction, Rect, TilingDirection},
@@ -57,7 +58,7 @@ fn move_tiling_window(
     .and_then(|parent| parent.as_split().cloned())
   {
     if split_parent.child_count() == 1 {
-      flatten_split_container(split_parent)?;
+      flatten_split_container(split_parent, state)?;
     }
   }
 
@@ -217,6 +218,15 @@ fn move_to_workspace_in_direction(
     .and_then(|monitor| monitor.displayed_workspace());
 
   if let Some(workspace) = workspace_in_direction {
+    if workspace.is_locked() {
+      warn!(
+        "Cannot move window to locked workspace: '{}'.",
+        workspace.config().name
+      );
+
+      return Ok(());
+    }
+
     // Since the window is crossing monitors, adjustments might need to be
     // made because of DPI.
     if monitor.has_dpi_difference(&workspace.clone().into())? {
@@ -288,6 +298,7 @@ fn invert_workspace_tiling_direction(
       split_container,
       workspace.clone().into(),
       workspace_children,
+      state,
     )?;
   }
 
@@ -311,7 +322,7 @@ fn invert_workspace_tiling_direction(
   // Workspace might have redundant split containers after the tiling
   // direction change. For example, V[H[1 2] 3] where container 3 is moved
   // up results in H[3 H[1 2]], and needs to be flattened to H[3 1 2].
-  flatten_child_split_containers(workspace.clone().into())?;
+  flatten_child_split_containers(workspace.clone().into(), state)?;
 
   // Resize the window such that the split container and window are each
   // 0.5.
@@ -402,7 +413,7 @@ fn new_floating_position(
   state: &mut WmState,
 ) -> anyhow::Result<Option<(Rect, Monitor)>> {
   let monitor = window_to_move.monitor().context("No monitor.")?;
-  let monitor_rect = monitor.native().working_rect()?.clone();
+  let monitor_rect = window_to_move.floating_bounds(&monitor)?;
   let window_pos = window_to_move.native().frame_position()?;
 
   let is_on_monitor_edge = match direction {
@@ -418,7 +429,7 @@ fn new_floating_position(
     let next_monitor = state.monitor_in_direction(&monitor, direction)?;
 
     if let Some(next_monitor) = next_monitor {
-      let monitor_rect = next_monitor.native().working_rect()?.clone();
+      let monitor_rect = window_to_move.floating_bounds(&next_monitor)?;
 
       let position = snap_to_monitor_edge(
         &window_pos,
@@ -0,0 +1,40 @@
+use super::move_window_to_workspace;
+use crate::{
+  common::Direction,
+  containers::{
+    commands::set_focused_descendant, traits::CommonGetters,
+    WindowContainer,
+  },
+  user_config::UserConfig,
+  wm_state::WmState,
+  workspaces::WorkspaceTarget,
+};
+
+/// Moves a window to the displayed workspace of the monitor in the given
+/// direction.
+///
+/// Unlike `move_window_to_workspace`, focus follows the window to the
+/// target monitor if the window was focused.
+pub fn move_window_to_monitor(
+  window: WindowContainer,
+  direction: &Direction,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let had_focus = window.has_focus(None);
+
+  move_window_to_workspace(
+    window.clone(),
+    WorkspaceTarget::Direction(direction.clone()),
+    state,
+    config,
+  )?;
+
+  if had_focus {
+    set_focused_descendant(window.into(), None);
+    state.pending_sync.focus_change = true;
+    state.pending_sync.cursor_jump = true;
+  }
+
+  Ok(())
+}
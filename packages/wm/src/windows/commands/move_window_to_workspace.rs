@@ -1,5 +1,5 @@
 use anyhow::Context;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::{
   containers::{
@@ -44,6 +44,15 @@ pub fn move_window_to_workspace(
       return Ok(());
     }
 
+    if target_workspace.is_locked() {
+      warn!(
+        "Cannot move window to locked workspace: '{}'.",
+        target_workspace.config().name
+      );
+
+      return Ok(());
+    }
+
     info!(
       "Moving window to workspace: '{}'.",
       target_workspace.config().name
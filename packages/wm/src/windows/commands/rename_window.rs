@@ -0,0 +1,18 @@
+use tracing::info;
+
+use crate::{containers::WindowContainer, windows::traits::WindowGetters};
+
+/// Gives the window a custom title, which is preferred over the native
+/// title in IPC responses.
+///
+/// The native title is also updated, though the application might
+/// overwrite it afterwards.
+pub fn rename_window(
+  window: WindowContainer,
+  title: &str,
+) -> anyhow::Result<()> {
+  info!("Renaming window to '{}'.", title);
+
+  window.set_custom_title(Some(title.to_string()));
+  window.native().set_title(title)
+}
@@ -7,6 +7,7 @@ use crate::{
     traits::{CommonGetters, PositionGetters, TilingSizeGetters},
     WindowContainer,
   },
+  user_config::UserConfig,
   wm_state::WmState,
 };
 
@@ -15,6 +16,7 @@ pub fn resize_window(
   width_delta: Option<LengthValue>,
   height_delta: Option<LengthValue>,
   state: &mut WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<()> {
   let monitor = window.monitor().context("No monitor")?;
   let monitor_rect = monitor.to_rect()?;
@@ -53,7 +55,7 @@ pub fn resize_window(
           .container_to_resize(false)?
           .and_then(|container| container.parent())
           .and_then(|parent| {
-            parent.to_rect().ok().map(|rect| rect.width())
+            parent.to_rect().ok().map(|rect| rect.height())
           })
           .map(|parent_height| {
             parent_height
@@ -61,7 +63,7 @@ pub fn resize_window(
                 * tiling_window.tiling_siblings().count() as i32
           }),
         _ => window.parent().and_then(|parent| {
-          parent.to_rect().ok().map(|rect| rect.width())
+          parent.to_rect().ok().map(|rect| rect.height())
         }),
       };
 
@@ -77,6 +79,7 @@ pub fn resize_window(
     target_width.map(|target_width| LengthValue::from_px(target_width)),
     target_height.map(|target_height| LengthValue::from_px(target_height)),
     state,
+    config,
   )?;
 
   Ok(())
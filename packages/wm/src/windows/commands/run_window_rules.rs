@@ -1,9 +1,14 @@
+use anyhow::Context;
 use tracing::info;
 
 use crate::{
-  containers::{traits::CommonGetters, WindowContainer},
-  user_config::{UserConfig, WindowRuleEvent},
-  windows::traits::WindowGetters,
+  common::Rect,
+  containers::{
+    traits::{CommonGetters, PositionGetters},
+    WindowContainer,
+  },
+  user_config::{FloatSizeConfig, UserConfig, WindowRuleEvent},
+  windows::{traits::WindowGetters, WindowState},
   wm_state::WmState,
 };
 
@@ -23,6 +28,32 @@ pub fn run_window_rules(
   for rule in pending_window_rules {
     info!("Running window rule with commands: {:?}.", rule.commands);
 
+    // The float size is only applied when the window is first managed,
+    // or when it first becomes floating below. Otherwise, the size would
+    // override manual resizing whenever the rule runs again (e.g. on
+    // title change).
+    let float_size = rule.float_size.as_ref();
+    let is_manage_event = event_type == WindowRuleEvent::Manage;
+    let was_floating =
+      matches!(subject_window.state(), WindowState::Floating(_));
+
+    // Resize before running the commands, so that the size is used if a
+    // command changes the window to floating.
+    if let Some(float_size) = float_size.filter(|_| is_manage_event) {
+      apply_float_size(&subject_window, float_size, state)?;
+    }
+
+    if let Some(ignore_exclusion_zones) = rule.ignore_exclusion_zones {
+      subject_window.set_ignore_exclusion_zones(ignore_exclusion_zones);
+    }
+
+    // Like the float size, the opacity is only applied when the window is
+    // first managed, so that it doesn't override `set-opacity`.
+    if let Some(opacity) = rule.opacity.filter(|_| is_manage_event) {
+      subject_window.set_opacity(Some(opacity));
+      state.pending_sync.reset_window_effects = true;
+    }
+
     for command in &rule.commands {
       command.run(subject_window.clone().into(), state, config)?;
 
@@ -37,6 +68,15 @@ pub fn run_window_rules(
       }
     }
 
+    let is_floating =
+      matches!(subject_window.state(), WindowState::Floating(_));
+
+    if let Some(float_size) = float_size
+      .filter(|_| !is_manage_event && !was_floating && is_floating)
+    {
+      apply_float_size(&subject_window, float_size, state)?;
+    }
+
     // Add the window rule as done.
     if rule.run_once {
       let window_rules = subject_window
@@ -50,3 +90,28 @@ pub fn run_window_rules(
 
   Ok(Some(subject_window))
 }
+
+/// Sets the floating placement of the window to the given size, centered
+/// on its workspace.
+fn apply_float_size(
+  window: &WindowContainer,
+  float_size: &FloatSizeConfig,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let workspace = window.workspace().context("No workspace.")?;
+
+  let floating_placement =
+    Rect::from_xy(0, 0, float_size.width as i32, float_size.height as i32)
+      .translate_to_center(&workspace.to_rect()?);
+
+  window.set_floating_placement(floating_placement);
+
+  if let WindowState::Floating(_) = window.state() {
+    state
+      .pending_sync
+      .containers_to_redraw
+      .push(window.clone().into());
+  }
+
+  Ok(())
+}
@@ -0,0 +1,49 @@
+use tracing::info;
+
+use super::unmanage_window;
+use crate::{
+  containers::WindowContainer, windows::traits::WindowGetters,
+  wm_state::WmState,
+};
+
+/// Assigns a window to the scratchpad with the given name and hides it.
+///
+/// Any window that was previously hidden in the scratchpad is shown again
+/// and gets managed as a regular window.
+pub fn set_scratchpad(
+  window: WindowContainer,
+  name: &str,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let native_window = window.native().clone();
+
+  // Window is already in the scratchpad. This is the case when window
+  // rules are re-run after the scratchpad window is shown.
+  if state.scratchpad_windows.get(name) == Some(&native_window) {
+    return Ok(());
+  }
+
+  info!("Moving window to scratchpad: '{}'.", name);
+
+  // A window can only be assigned to a single scratchpad.
+  state
+    .scratchpad_windows
+    .retain(|_, scratchpad_window| *scratchpad_window != native_window);
+
+  let prev_window = state
+    .scratchpad_windows
+    .insert(name.to_string(), native_window.clone());
+
+  // Show the previous window if it's currently hidden. It'll then get
+  // managed by the handler for `PlatformEvent::WindowShown`.
+  if let Some(prev_window) = prev_window {
+    if state.window_from_native(&prev_window).is_none() {
+      prev_window.show()?;
+    }
+  }
+
+  unmanage_window(window, state)?;
+  native_window.hide()?;
+
+  Ok(())
+}
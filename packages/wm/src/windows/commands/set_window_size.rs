@@ -1,12 +1,16 @@
 use anyhow::Context;
+use tracing::warn;
 
 use crate::{
   common::{LengthValue, Rect},
   containers::{
-    commands::resize_tiling_container,
-    traits::{CommonGetters, PositionGetters, TilingSizeGetters},
+    commands::resize_tiling_container_with_min,
+    traits::{
+      CommonGetters, PositionGetters, TilingSizeGetters, MIN_TILING_SIZE,
+    },
     WindowContainer,
   },
+  user_config::UserConfig,
   windows::{
     traits::WindowGetters, NonTilingWindow, TilingWindow, WindowState,
   },
@@ -22,10 +26,17 @@ pub fn set_window_size(
   target_width: Option<LengthValue>,
   target_height: Option<LengthValue>,
   state: &mut WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<()> {
   match window {
     WindowContainer::TilingWindow(window) => {
-      set_tiling_window_size(window, target_width, target_height, state)?;
+      set_tiling_window_size(
+        window,
+        target_width,
+        target_height,
+        state,
+        config,
+      )?;
     }
     WindowContainer::NonTilingWindow(window) => {
       if matches!(window.state(), WindowState::Floating(_)) {
@@ -47,13 +58,26 @@ fn set_tiling_window_size(
   target_width: Option<LengthValue>,
   target_height: Option<LengthValue>,
   state: &mut WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<()> {
   if let Some(target_width) = target_width {
-    set_tiling_window_length(window.clone(), target_width, true, state)?;
+    set_tiling_window_length(
+      window.clone(),
+      target_width,
+      true,
+      state,
+      config,
+    )?;
   }
 
   if let Some(target_height) = target_height {
-    set_tiling_window_length(window.clone(), target_height, false, state)?;
+    set_tiling_window_length(
+      window.clone(),
+      target_height,
+      false,
+      state,
+      config,
+    )?;
   }
 
   Ok(())
@@ -65,6 +89,7 @@ fn set_tiling_window_length(
   target_length: LengthValue,
   is_width_resize: bool,
   state: &mut WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<()> {
   let monitor = window.monitor().context("No monitor")?;
   let monitor_rect = monitor.to_rect()?;
@@ -88,12 +113,42 @@ fn set_tiling_window_length(
       }
     };
 
+    let min_length = match is_width_resize {
+      true => config.value.window_behavior.min_tile_width,
+      false => config.value.window_behavior.min_tile_height,
+    } as i32;
+
+    let container_count =
+      container_to_resize.tiling_siblings().count() as i32 + 1;
+
+    // Prevent the container and its siblings from being resized below the
+    // minimum length. Fall back to the default minimum tiling size if
+    // there isn't enough space to fit all containers at that length.
+    let min_tiling_size =
+      match parent_length >= min_length * container_count {
+        true => LengthValue::from_px(min_length)
+          .to_percentage(parent_length)
+          .max(MIN_TILING_SIZE),
+        false => {
+          warn!(
+            "Not enough space to fit {} tiling windows at minimum size.",
+            container_count
+          );
+
+          MIN_TILING_SIZE
+        }
+      };
+
     // Convert the target length to a tiling size.
     let tiling_size = target_length.to_percentage(parent_length);
 
     // Skip the resize if the window is already at the target size.
     if container_to_resize.tiling_size() - tiling_size != 0. {
-      resize_tiling_container(&container_to_resize, tiling_size);
+      resize_tiling_container_with_min(
+        &container_to_resize,
+        tiling_size,
+        min_tiling_size,
+      );
 
       state
         .pending_sync
@@ -0,0 +1,38 @@
+use crate::{
+  common::Direction,
+  containers::{
+    commands::{swap_containers, tiling_focus_target},
+    traits::CommonGetters,
+  },
+  windows::TilingWindow,
+  wm_event::WmEvent,
+  wm_state::WmState,
+};
+
+/// Swaps a tiling window with the nearest tiling window in the given
+/// direction. Unlike `move_window_in_direction`, the layout and tiling
+/// sizes are kept as-is, and focus stays on the swapped window.
+pub fn swap_window_in_direction(
+  window: TilingWindow,
+  direction: &Direction,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let target = tiling_focus_target(window.clone().into(), direction)?;
+
+  if let Some(target) = target {
+    swap_containers(&window.clone().into(), &target)?;
+
+    state
+      .pending_sync
+      .containers_to_redraw
+      .extend([window.clone().into(), target]);
+
+    if window.has_focus(None) {
+      state.emit_event(WmEvent::FocusedContainerMoved {
+        focused_container: window.to_dto()?,
+      });
+    }
+  }
+
+  Ok(())
+}
@@ -0,0 +1,107 @@
+use anyhow::Context;
+use tracing::{info, warn};
+
+use super::{
+  manage_window, move_window_to_workspace, unmanage_window,
+  update_window_state,
+};
+use crate::{
+  containers::{
+    commands::set_focused_descendant,
+    traits::{CommonGetters, PositionGetters},
+  },
+  user_config::{FloatingStateConfig, UserConfig},
+  windows::{traits::WindowGetters, WindowState},
+  wm_state::WmState,
+  workspaces::WorkspaceTarget,
+};
+
+/// Toggles the visibility of the window in the scratchpad with the given
+/// name.
+///
+/// Hidden scratchpad windows are shown as centered floating windows on
+/// the focused workspace. If the window is already shown on a different
+/// workspace, it's moved to the focused workspace instead.
+pub fn toggle_scratchpad(
+  name: &str,
+  state: &mut WmState,
+  config: &mut UserConfig,
+) -> anyhow::Result<()> {
+  let native_window = match state.scratchpad_windows.get(name) {
+    Some(native_window) => native_window.clone(),
+    None => {
+      warn!("No window in scratchpad: '{}'.", name);
+      return Ok(());
+    }
+  };
+
+  let focused_workspace = state
+    .focused_container()
+    .and_then(|focused| focused.workspace())
+    .context("No workspace is currently focused.")?;
+
+  match state.window_from_native(&native_window) {
+    // Hide the window if it's shown on a displayed workspace.
+    Some(window)
+      if window
+        .workspace()
+        .map(|workspace| workspace.is_displayed())
+        .unwrap_or(false) =>
+    {
+      info!("Hiding scratchpad: '{}'.", name);
+      unmanage_window(window, state)?;
+      native_window.hide()?;
+    }
+    Some(window) => {
+      info!("Moving scratchpad to focused workspace: '{}'.", name);
+
+      move_window_to_workspace(
+        window.clone(),
+        WorkspaceTarget::Name(focused_workspace.config().name),
+        state,
+        config,
+      )?;
+
+      set_focused_descendant(window.into(), None);
+      state.pending_sync.focus_change = true;
+    }
+    None => {
+      info!("Showing scratchpad: '{}'.", name);
+      manage_window(native_window.clone(), None, state, config)?;
+
+      // Window might fail to get managed if it has since been destroyed.
+      let window = match state.window_from_native(&native_window) {
+        Some(window) => window,
+        None => {
+          state.scratchpad_windows.remove(name);
+          return Ok(());
+        }
+      };
+
+      window.set_floating_placement(
+        window
+          .floating_placement()
+          .translate_to_center(&focused_workspace.to_rect()?),
+      );
+
+      let floating_defaults =
+        &config.value.window_behavior.state_defaults.floating;
+
+      let window = update_window_state(
+        window.clone(),
+        WindowState::Floating(FloatingStateConfig {
+          centered: true,
+          shown_on_top: floating_defaults.shown_on_top,
+        }),
+        state,
+        config,
+      )?;
+
+      set_focused_descendant(window.clone().into(), None);
+      state.pending_sync.focus_change = true;
+      state.pending_sync.containers_to_redraw.push(window.into());
+    }
+  }
+
+  Ok(())
+}
@@ -0,0 +1,33 @@
+use tracing::info;
+
+use super::manage_window;
+use crate::{
+  common::platform::NativeWindow, user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Removes a window from the ignored windows, and manages it again if
+/// it's manageable.
+///
+/// Does nothing if the window isn't ignored.
+pub fn unignore_window(
+  native_window: NativeWindow,
+  state: &mut WmState,
+  config: &mut UserConfig,
+) -> anyhow::Result<()> {
+  let ignored_index = state
+    .ignored_windows
+    .iter()
+    .position(|ignored_window| *ignored_window == native_window);
+
+  if let Some(ignored_index) = ignored_index {
+    info!("Unignoring window");
+    state.ignored_windows.remove(ignored_index);
+
+    if native_window.is_manageable().unwrap_or(false) {
+      manage_window(native_window, None, state, config)?;
+    }
+  }
+
+  Ok(())
+}
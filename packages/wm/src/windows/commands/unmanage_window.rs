@@ -25,13 +25,18 @@ pub fn unmanage_window(
   let focus_target =
     state.focus_target_after_removal(&window.clone().into());
 
-  detach_container(window.clone().into())?;
+  detach_container(window.clone().into(), state)?;
+  state.dialog_owners.remove(&window.id());
+
+  // Restore the window's title bar in case it was removed. This fails if
+  // the window has already been destroyed, which is fine to ignore.
+  _ = window.native().set_title_bar_visibility(true);
 
   // After detaching the container, flatten any redundant split containers.
   // For example, in the layout V[1 H[2]] where container 1 is detached to
   // become V[H[2]], this will then need to be flattened to V[2].
   for ancestor in ancestors.iter().rev() {
-    flatten_child_split_containers(ancestor.clone())?;
+    flatten_child_split_containers(ancestor.clone(), state)?;
   }
 
   state.emit_event(WmEvent::WindowUnmanaged {
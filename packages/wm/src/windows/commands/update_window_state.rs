@@ -81,6 +81,7 @@ fn set_tiling(
     tiling_window.clone().into(),
     window.parent().context("No parent")?,
     window.index(),
+    state,
   )?;
 
   move_container_within_tree(
@@ -158,6 +159,7 @@ fn set_non_tiling(
         non_tiling_window.clone().into(),
         workspace.clone().into(),
         window.index(),
+        state,
       )?;
 
       let changed_containers =
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+
+use crate::{
+  app_command::InvokeCommand,
+  common::{platform::Platform, DisplayState},
+  containers::{commands::set_focused_descendant, traits::CommonGetters},
+  user_config::{BindingModeConfig, KeybindingConfig},
+  windows::{traits::WindowGetters, WindowState},
+  wm_event::WmEvent,
+  wm_state::{WindowSelection, WmState},
+};
+
+/// Name of the binding mode that's enabled during window selection.
+const WINDOW_SELECT_MODE: &str = "window_select";
+
+/// Toggles window selection mode.
+///
+/// While active, a label (a-z) is shown over each visible window, and
+/// pressing a label's key focuses its window. Pressing escape exits
+/// without changing focus.
+pub fn toggle_window_select_mode(
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  if state.window_selection.is_some() {
+    return exit_window_select_mode(state);
+  }
+
+  let windows = state.windows().into_iter().filter(|window| {
+    window.display_state() == DisplayState::Shown
+      && window.state() != WindowState::Minimized
+  });
+
+  let labels = ('a'..='z').zip(windows).collect::<Vec<_>>();
+
+  if labels.is_empty() {
+    return Ok(());
+  }
+
+  let overlays = Platform::show_window_labels(
+    labels
+      .iter()
+      .map(|(label, window)| {
+        anyhow::Ok((*label, window.native().frame_position()?))
+      })
+      .try_collect()?,
+  )?;
+
+  // Capture the label keys via a binding mode.
+  let keybindings = labels
+    .iter()
+    .map(|(label, _)| KeybindingConfig {
+      bindings: vec![label.to_string()],
+      commands: vec![InvokeCommand::SelectWindow { label: *label }],
+    })
+    .chain([KeybindingConfig {
+      bindings: vec!["escape".to_string()],
+      commands: vec![InvokeCommand::WindowSelectMode],
+    }])
+    .collect();
+
+  let previous_binding_modes = std::mem::replace(
+    &mut state.binding_modes,
+    vec![BindingModeConfig {
+      name: WINDOW_SELECT_MODE.to_string(),
+      display_name: Some("Select window".to_string()),
+      keybindings,
+    }],
+  );
+
+  state.window_selection = Some(WindowSelection {
+    window_ids: labels
+      .into_iter()
+      .map(|(label, window)| (label, window.id()))
+      .collect::<HashMap<_, _>>(),
+    overlays,
+    previous_binding_modes,
+  });
+
+  state.emit_event(WmEvent::BindingModesChanged {
+    new_binding_modes: state.binding_modes.clone(),
+  });
+
+  Ok(())
+}
+
+/// Focuses the window with the given label and exits window selection
+/// mode.
+pub fn select_window(
+  label: char,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let window = state
+    .window_selection
+    .as_ref()
+    .context("Window selection mode is not active.")?
+    .window_ids
+    .get(&label)
+    .and_then(|id| state.container_by_id(*id));
+
+  exit_window_select_mode(state)?;
+
+  // Window might have since been unmanaged.
+  if let Some(window) = window {
+    set_focused_descendant(window, None);
+    state.pending_sync.focus_change = true;
+  }
+
+  Ok(())
+}
+
+fn exit_window_select_mode(state: &mut WmState) -> anyhow::Result<()> {
+  let selection = state.window_selection.take();
+
+  // Restore the binding modes that were active before entering window
+  // selection mode.
+  match &selection {
+    Some(selection) => {
+      state.binding_modes = selection.previous_binding_modes.clone();
+    }
+    None => state
+      .binding_modes
+      .retain(|binding_mode| binding_mode.name != WINDOW_SELECT_MODE),
+  }
+
+  state.emit_event(WmEvent::BindingModesChanged {
+    new_binding_modes: state.binding_modes.clone(),
+  });
+
+  if let Some(mut selection) = selection {
+    selection.overlays.destroy()?;
+  }
+
+  Ok(())
+}
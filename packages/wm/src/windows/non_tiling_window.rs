@@ -37,6 +37,9 @@ struct NonTilingWindowInner {
   insertion_target: Option<(Container, usize)>,
   display_state: DisplayState,
   border_delta: RectDelta,
+  opacity: Option<u8>,
+  custom_title: Option<String>,
+  ignore_exclusion_zones: bool,
   has_pending_dpi_adjustment: bool,
   floating_placement: Rect,
   done_window_rules: Vec<WindowRuleConfig>,
@@ -50,6 +53,9 @@ impl NonTilingWindow {
     state: WindowState,
     prev_state: Option<WindowState>,
     border_delta: RectDelta,
+    opacity: Option<u8>,
+    custom_title: Option<String>,
+    ignore_exclusion_zones: bool,
     insertion_target: Option<(Container, usize)>,
     floating_placement: Rect,
     done_window_rules: Vec<WindowRuleConfig>,
@@ -66,6 +72,9 @@ impl NonTilingWindow {
       insertion_target,
       display_state: DisplayState::Shown,
       border_delta,
+      opacity,
+      custom_title,
+      ignore_exclusion_zones,
       has_pending_dpi_adjustment: false,
       floating_placement,
       done_window_rules,
@@ -92,6 +101,9 @@ impl NonTilingWindow {
       self.native().clone(),
       Some(self.state()),
       self.border_delta(),
+      self.opacity(),
+      self.custom_title(),
+      self.ignore_exclusion_zones(),
       self.floating_placement(),
       inner_gap,
       self.done_window_rules(),
@@ -117,8 +129,9 @@ impl NonTilingWindow {
       border_delta: self.border_delta(),
       floating_placement: self.floating_placement(),
       handle: self.native().handle,
-      title: self.native().title()?,
+      title: self.display_title()?,
       class_name: self.native().class_name()?,
+      process_id: self.native().process_id()?,
       process_name: self.native().process_name()?,
       active_drag: self.active_drag(),
     }))
@@ -44,6 +44,9 @@ struct TilingWindowInner {
   prev_state: Option<WindowState>,
   display_state: DisplayState,
   border_delta: RectDelta,
+  opacity: Option<u8>,
+  custom_title: Option<String>,
+  ignore_exclusion_zones: bool,
   has_pending_dpi_adjustment: bool,
   floating_placement: Rect,
   inner_gap: LengthValue,
@@ -57,6 +60,9 @@ impl TilingWindow {
     native: NativeWindow,
     prev_state: Option<WindowState>,
     border_delta: RectDelta,
+    opacity: Option<u8>,
+    custom_title: Option<String>,
+    ignore_exclusion_zones: bool,
     floating_placement: Rect,
     inner_gap: LengthValue,
     done_window_rules: Vec<WindowRuleConfig>,
@@ -73,6 +79,9 @@ impl TilingWindow {
       prev_state,
       display_state: DisplayState::Shown,
       border_delta,
+      opacity,
+      custom_title,
+      ignore_exclusion_zones,
       has_pending_dpi_adjustment: false,
       floating_placement,
       inner_gap,
@@ -94,6 +103,9 @@ impl TilingWindow {
       state,
       Some(WindowState::Tiling),
       self.border_delta(),
+      self.opacity(),
+      self.custom_title(),
+      self.ignore_exclusion_zones(),
       insertion_target,
       self.floating_placement(),
       self.done_window_rules(),
@@ -119,8 +131,9 @@ impl TilingWindow {
       border_delta: self.border_delta(),
       floating_placement: self.floating_placement(),
       handle: self.native().handle,
-      title: self.native().title()?,
+      title: self.display_title()?,
       class_name: self.native().class_name()?,
+      process_id: self.native().process_id()?,
       process_name: self.native().process_name()?,
       active_drag: self.active_drag(),
     }))
@@ -6,6 +6,7 @@ use crate::{
   common::{
     platform::NativeWindow, DisplayState, LengthValue, Rect, RectDelta,
   },
+  monitors::Monitor,
   user_config::{UserConfig, WindowRuleConfig},
   windows::{active_drag::ActiveDrag, WindowState},
 };
@@ -65,6 +66,42 @@ pub trait WindowGetters {
 
   fn set_border_delta(&self, border_delta: RectDelta);
 
+  /// Opacity override (as a percentage) set via the `set-opacity`
+  /// command. Takes precedence over the transparency window effect.
+  fn opacity(&self) -> Option<u8>;
+
+  fn set_opacity(&self, opacity: Option<u8>);
+
+  /// Custom title set via the `rename-window` command. Takes precedence
+  /// over the native title in IPC responses.
+  fn custom_title(&self) -> Option<String>;
+
+  fn set_custom_title(&self, custom_title: Option<String>);
+
+  /// Whether the window can be placed over the reserved areas of a
+  /// monitor (e.g. the taskbar) while floating. Set via the
+  /// `ignore_exclusion_zones` window rule option.
+  fn ignore_exclusion_zones(&self) -> bool;
+
+  fn set_ignore_exclusion_zones(&self, ignore_exclusion_zones: bool);
+
+  /// Gets the area of the monitor that the window is kept within while
+  /// floating.
+  fn floating_bounds(&self, monitor: &Monitor) -> anyhow::Result<Rect> {
+    match self.ignore_exclusion_zones() {
+      true => monitor.native().rect().cloned(),
+      false => monitor.native().working_rect().cloned(),
+    }
+  }
+
+  /// Gets the custom title if one is set, otherwise the native title.
+  fn display_title(&self) -> anyhow::Result<String> {
+    match self.custom_title() {
+      Some(custom_title) => Ok(custom_title),
+      None => self.native().title(),
+    }
+  }
+
   fn total_border_delta(&self) -> anyhow::Result<RectDelta> {
     let border_delta = self.border_delta();
     let shadow_border_delta = self.native().shadow_border_delta()?;
@@ -117,7 +154,8 @@ pub trait WindowGetters {
 ///
 /// Expects that the struct has a wrapping `RefCell` containing a struct
 /// with a `state`, `prev_state`, `native`, `has_pending_dpi_adjustment`,
-/// `border_delta`, `display_state`, and a `done_window_rules` field.
+/// `border_delta`, `opacity`, `custom_title`, `ignore_exclusion_zones`,
+/// `display_state`, and a `done_window_rules` field.
 #[macro_export]
 macro_rules! impl_window_getters {
   ($struct_name:ident) => {
@@ -150,6 +188,31 @@ macro_rules! impl_window_getters {
         self.0.borrow_mut().border_delta = border_delta;
       }
 
+      fn opacity(&self) -> Option<u8> {
+        self.0.borrow().opacity
+      }
+
+      fn set_opacity(&self, opacity: Option<u8>) {
+        self.0.borrow_mut().opacity = opacity;
+      }
+
+      fn custom_title(&self) -> Option<String> {
+        self.0.borrow().custom_title.clone()
+      }
+
+      fn set_custom_title(&self, custom_title: Option<String>) {
+        self.0.borrow_mut().custom_title = custom_title;
+      }
+
+      fn ignore_exclusion_zones(&self) -> bool {
+        self.0.borrow().ignore_exclusion_zones
+      }
+
+      fn set_ignore_exclusion_zones(&self, ignore_exclusion_zones: bool) {
+        self.0.borrow_mut().ignore_exclusion_zones =
+          ignore_exclusion_zones;
+      }
+
       fn display_state(&self) -> DisplayState {
         self.0.borrow().display_state.clone()
       }
@@ -26,6 +26,7 @@ pub struct WindowDto {
   pub handle: isize,
   pub title: String,
   pub class_name: String,
+  pub process_id: u32,
   pub process_name: String,
   pub active_drag: Option<ActiveDrag>,
 }
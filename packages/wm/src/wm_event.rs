@@ -61,3 +61,12 @@ pub enum WmEvent {
     updated_workspace: ContainerDto,
   },
 }
+
+/// A WM event that was emitted, along with the time it was emitted at.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WmEventLogEntry {
+  /// Milliseconds since the Unix epoch.
+  pub timestamp: u128,
+  pub event: WmEvent,
+}
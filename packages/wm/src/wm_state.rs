@@ -1,7 +1,14 @@
-use std::{collections::HashSet, time::Instant};
+use std::{
+  cell::RefCell,
+  collections::{HashMap, HashSet, VecDeque},
+  time::{Instant, SystemTime, UNIX_EPOCH},
+};
 
 use anyhow::Context;
-use tokio::sync::mpsc::{self};
+use tokio::{
+  sync::mpsc::{self},
+  task::JoinHandle,
+};
 use tracing::warn;
 use uuid::Uuid;
 
@@ -9,7 +16,7 @@ use crate::{
   cleanup::run_cleanup,
   common::{
     commands::platform_sync,
-    platform::{NativeMonitor, NativeWindow, Platform},
+    platform::{NativeMonitor, NativeWindow, Platform, WindowLabels},
     Direction, Point,
   },
   containers::{
@@ -18,12 +25,19 @@ use crate::{
     Container, RootContainer, WindowContainer,
   },
   monitors::{commands::add_monitor, Monitor},
+  session::Session,
   user_config::{BindingModeConfig, UserConfig},
   windows::{commands::manage_window, traits::WindowGetters, WindowState},
-  wm_event::WmEvent,
-  workspaces::{Workspace, WorkspaceTarget},
+  wm_event::{WmEvent, WmEventLogEntry},
+  workspaces::{commands::activate_workspace, Workspace, WorkspaceTarget},
 };
 
+/// Maximum number of entries kept in `WmState::event_log`.
+const EVENT_LOG_SIZE: usize = 100;
+
+/// Maximum number of entries kept in `WmState::window_history`.
+const WINDOW_HISTORY_SIZE: usize = 50;
+
 pub struct WmState {
   /// Root node of the container tree. Monitors are the children of the
   /// root node, followed by workspaces, then split containers/windows.
@@ -37,6 +51,19 @@ pub struct WmState {
   /// workspace focus.
   pub recent_workspace_name: Option<String>,
 
+  /// Names of previously displayed workspaces by monitor ID, with the
+  /// most recent last.
+  ///
+  /// Used for the `switch-to-previous-workspace` command.
+  pub workspace_history: HashMap<Uuid, Vec<String>>,
+
+  /// IDs of recently focused windows across all workspaces, with the
+  /// currently focused window last. Holds at most `WINDOW_HISTORY_SIZE`
+  /// IDs, and might include windows that have since been unmanaged.
+  ///
+  /// Used for the `focus-previous-window` command.
+  pub window_history: Vec<Uuid>,
+
   /// Container that most recently had focus synced.
   ///
   /// Used for updating window effects on focus change.
@@ -47,6 +74,18 @@ pub struct WmState {
   /// Used to decide whether to override incoming focus events.
   pub unmanaged_or_minimized_timestamp: Option<Instant>,
 
+  /// ID of the window underneath the cursor and the time since the cursor
+  /// started hovering over it.
+  ///
+  /// Used for the `general.focus_follows_cursor_delay_ms` option.
+  pub cursor_hover_start: Option<(Uuid, Instant)>,
+
+  /// Timer for re-checking the window under the cursor once the
+  /// `general.focus_follows_cursor_delay_ms` delay has elapsed.
+  ///
+  /// Aborted when the cursor moves onto a different window.
+  pub cursor_hover_timer: Option<JoinHandle<()>>,
+
   /// Configs of currently enabled binding modes.
   pub binding_modes: Vec<BindingModeConfig>,
 
@@ -54,6 +93,37 @@ pub struct WmState {
   /// `ignore` command.
   pub ignored_windows: Vec<NativeWindow>,
 
+  /// Windows assigned to a named scratchpad. Windows can be added via the
+  /// `set-scratchpad` command, and are unmanaged while hidden.
+  pub scratchpad_windows: HashMap<String, NativeWindow>,
+
+  /// Windows assigned to a mark (a-z). Windows can be marked via the
+  /// `mark` command, and focused via the `jump-to-mark` command.
+  pub window_marks: HashMap<char, NativeWindow>,
+
+  /// IDs of the managed owner windows of dialogs, by the dialog's ID.
+  ///
+  /// Used for unmanaging dialogs along with their owner.
+  pub dialog_owners: HashMap<Uuid, Uuid>,
+
+  /// Labeled windows and their overlays while window selection mode is
+  /// active.
+  ///
+  /// Used for the `window-select-mode` and `select-window` commands.
+  pub window_selection: Option<WindowSelection>,
+
+  /// Most recently emitted WM events, with the oldest event first. Holds
+  /// at most `EVENT_LOG_SIZE` events.
+  ///
+  /// Used for debugging via the `query event-log` command.
+  event_log: RefCell<VecDeque<WmEventLogEntry>>,
+
+  /// Containers in the tree by their ID. Kept up-to-date by the commands
+  /// that attach and detach containers.
+  ///
+  /// Used for lookups via `container_by_id`.
+  container_index: HashMap<Uuid, Container>,
+
   /// Whether the initial state has been populated.
   has_initialized: bool,
 
@@ -80,13 +150,31 @@ pub struct PendingSync {
   pub cursor_jump: bool,
 }
 
+pub struct WindowSelection {
+  /// IDs of the labeled windows by their label.
+  pub window_ids: HashMap<char, Uuid>,
+
+  /// Overlays showing the labels.
+  pub overlays: WindowLabels,
+
+  /// Binding modes that were active before entering window selection
+  /// mode. These are restored on exit.
+  pub previous_binding_modes: Vec<BindingModeConfig>,
+}
+
 impl WmState {
   pub fn new(
     event_tx: mpsc::UnboundedSender<WmEvent>,
     exit_tx: mpsc::UnboundedSender<()>,
   ) -> Self {
+    let root_container = RootContainer::new();
+
     Self {
-      root_container: RootContainer::new(),
+      container_index: HashMap::from([(
+        root_container.id(),
+        root_container.clone().into(),
+      )]),
+      root_container,
       pending_sync: PendingSync {
         containers_to_redraw: Vec::new(),
         focus_change: false,
@@ -95,9 +183,18 @@ impl WmState {
       },
       recent_focused_container: None,
       recent_workspace_name: None,
+      workspace_history: HashMap::new(),
+      window_history: Vec::new(),
       unmanaged_or_minimized_timestamp: None,
+      cursor_hover_start: None,
+      cursor_hover_timer: None,
       binding_modes: Vec::new(),
       ignored_windows: Vec::new(),
+      scratchpad_windows: HashMap::new(),
+      window_marks: HashMap::new(),
+      dialog_owners: HashMap::new(),
+      window_selection: None,
+      event_log: RefCell::new(VecDeque::with_capacity(EVENT_LOG_SIZE)),
       has_initialized: false,
       event_tx,
       exit_tx,
@@ -119,10 +216,26 @@ impl WmState {
       add_monitor(native_monitor, self, config)?;
     }
 
+    // Get the layout saved on the previous shutdown (if enabled).
+    let mut session = match config.value.general.restore_session {
+      true => Session::read().unwrap_or_else(|err| {
+        warn!("Failed to read session: {:?}", err);
+        Session::default()
+      }),
+      false => Session::default(),
+    };
+
     for native_window in Platform::manageable_windows()? {
-      let nearest_workspace = self
-        .nearest_monitor(&native_window)
-        .and_then(|m| m.displayed_workspace());
+      // Use the workspace from the saved session if one is found.
+      let session_workspace = session
+        .take_workspace_name(&native_window)
+        .and_then(|name| self.restored_workspace(&name, config));
+
+      let nearest_workspace = session_workspace.or_else(|| {
+        self
+          .nearest_monitor(&native_window)
+          .and_then(|m| m.displayed_workspace())
+      });
 
       if let Some(workspace) = nearest_workspace {
         manage_window(
@@ -134,6 +247,12 @@ impl WmState {
       }
     }
 
+    // Move the restored windows to their saved position in the tiling
+    // tree.
+    if let Err(err) = session.restore_layouts(self, config) {
+      warn!("Failed to restore session layout: {:?}", err);
+    }
+
     let container_to_focus = self
       .window_from_native(&foreground_window)
       .map(|c| c.as_container())
@@ -151,6 +270,30 @@ impl WmState {
     Ok(())
   }
 
+  /// Gets the workspace to restore a window to from the saved session.
+  ///
+  /// Activates the workspace if it isn't already active. Returns `None`
+  /// if the workspace no longer exists in the user config.
+  fn restored_workspace(
+    &mut self,
+    workspace_name: &str,
+    config: &UserConfig,
+  ) -> Option<Workspace> {
+    if let Some(workspace) = self.workspace_by_name(workspace_name) {
+      return Some(workspace);
+    }
+
+    config.workspace_config_index(workspace_name)?;
+
+    match activate_workspace(Some(workspace_name), None, self, config) {
+      Ok(_) => self.workspace_by_name(workspace_name),
+      Err(err) => {
+        warn!("Failed to restore workspace: {:?}", err);
+        None
+      }
+    }
+  }
+
   pub fn monitors(&self) -> Vec<Monitor> {
     self.root_container.monitors()
   }
@@ -250,14 +393,64 @@ impl WmState {
   }
 
   /// Gets window that corresponds to the given `NativeWindow`.
+  ///
+  /// Window handles can get reused by the OS after a window is destroyed,
+  /// so the process ID is also compared (if it can still be retrieved).
+  /// The more expensive process creation time check is only done via
+  /// `stale_windows_from_native`.
   pub fn window_from_native(
     &self,
     native_window: &NativeWindow,
   ) -> Option<WindowContainer> {
+    self.windows().into_iter().find(|window| {
+      &*window.native() == native_window
+        && !matches!(
+          (window.native().process_id(), native_window.process_id()),
+          (Ok(managed_id), Ok(native_id)) if managed_id != native_id
+        )
+    })
+  }
+
+  /// Gets windows that have the same handle as the given `NativeWindow`,
+  /// but that belong to a different process. This can happen if the
+  /// original window was destroyed without the WM being notified.
+  ///
+  /// Should be called before `window_from_native` when a window is shown,
+  /// since a reused process ID isn't detected by `window_from_native`.
+  pub fn stale_windows_from_native(
+    &self,
+    native_window: &NativeWindow,
+  ) -> Vec<WindowContainer> {
     self
       .windows()
       .into_iter()
-      .find(|window| &*window.native() == native_window)
+      .filter(|window| {
+        &*window.native() == native_window
+          && Self::is_stale_native(&window.native(), native_window)
+      })
+      .collect()
+  }
+
+  /// Whether the managed `NativeWindow` belongs to a different process
+  /// than a `NativeWindow` with the same handle.
+  ///
+  /// Process ID's can also get reused, so the process creation time is
+  /// compared as well.
+  fn is_stale_native(
+    managed_window: &NativeWindow,
+    native_window: &NativeWindow,
+  ) -> bool {
+    match (managed_window.process_id(), native_window.process_id()) {
+      (Ok(managed_id), Ok(native_id)) if managed_id != native_id => true,
+      (Ok(_), Ok(_)) => match (
+        managed_window.process_creation_time(),
+        native_window.process_creation_time(),
+      ) {
+        (Ok(managed_time), Ok(native_time)) => managed_time != native_time,
+        _ => false,
+      },
+      _ => false,
+    }
   }
 
   pub fn workspace_by_name(
@@ -379,6 +572,20 @@ impl WmState {
     self.root_container.descendant_focus_order().next()
   }
 
+  /// Moves the given window ID to the end of the window history.
+  pub fn push_window_history(&mut self, window_id: Uuid) {
+    if self.window_history.last() == Some(&window_id) {
+      return;
+    }
+
+    self.window_history.retain(|id| *id != window_id);
+    self.window_history.push(window_id);
+
+    if self.window_history.len() > WINDOW_HISTORY_SIZE {
+      self.window_history.remove(0);
+    }
+  }
+
   /// Emits a WM event through an MSPC channel.
   ///
   /// Does not emit events while the WM is populating initial state. This
@@ -386,23 +593,65 @@ impl WmState {
   /// emitted via IPC server before the initial state is prepared.
   pub fn emit_event(&self, event: WmEvent) {
     if self.has_initialized {
+      let mut event_log = self.event_log.borrow_mut();
+
+      if event_log.len() == EVENT_LOG_SIZE {
+        event_log.pop_front();
+      }
+
+      let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default();
+
+      event_log.push_back(WmEventLogEntry {
+        timestamp,
+        event: event.clone(),
+      });
+
       if let Err(err) = self.event_tx.send(event) {
         warn!("Failed to send event: {}", err);
       }
     }
   }
 
+  /// Gets the most recently emitted WM events, with the oldest event
+  /// first.
+  pub fn event_log(&self) -> Vec<WmEventLogEntry> {
+    self.event_log.borrow().iter().cloned().collect()
+  }
+
   /// Starts graceful shutdown via an MSPC channel.
   pub fn emit_exit(&self) {
     self.exit_tx.send(()).unwrap()
   }
 
+  /// Gets the container with the given ID if it's in the tree.
   pub fn container_by_id(&self, id: Uuid) -> Option<Container> {
-    self
-      .root_container
-      .self_and_descendants()
-      .into_iter()
-      .find(|container| container.id() == id)
+    self.container_index.get(&id).cloned()
+  }
+
+  /// Adds the container and its descendants to the ID index, if the
+  /// parent it was attached to is part of the tree.
+  pub fn add_to_container_index(
+    &mut self,
+    container: &Container,
+    parent: &Container,
+  ) {
+    if !self.container_index.contains_key(&parent.id()) {
+      return;
+    }
+
+    for descendant in container.self_and_descendants() {
+      self.container_index.insert(descendant.id(), descendant);
+    }
+  }
+
+  /// Removes the container and its descendants from the ID index.
+  pub fn remove_from_container_index(&mut self, container: &Container) {
+    for descendant in container.self_and_descendants() {
+      self.container_index.remove(&descendant.id());
+    }
   }
 
   /// Gets container to focus after the given window is unmanaged,
@@ -485,10 +734,13 @@ impl WmState {
 
 impl Drop for WmState {
   fn drop(&mut self) {
+    // Include hidden scratchpad windows, since they are no longer part of
+    // the container tree.
     let managed_windows = self
       .windows()
       .into_iter()
       .map(|window| window.native().clone())
+      .chain(self.scratchpad_windows.values().cloned())
       .collect::<Vec<_>>();
 
     run_cleanup(managed_windows);
@@ -61,7 +61,7 @@ pub fn activate_workspace(
 
   let workspace = Workspace::new(
     workspace_config.clone(),
-    config.value.gaps.outer_gap.clone(),
+    config.outer_gap(&workspace_config),
     tiling_direction,
   );
 
@@ -70,6 +70,7 @@ pub fn activate_workspace(
     &workspace.clone().into(),
     &target_monitor.clone().into(),
     None,
+    state,
   )?;
 
   sort_workspaces(target_monitor.clone(), config)?;
@@ -88,11 +89,21 @@ fn workspace_config(
   state: &mut WmState,
   config: &UserConfig,
 ) -> anyhow::Result<WorkspaceConfig> {
-  let found_config = match workspace_name {
+  let active_workspaces = state.workspaces();
+
+  match workspace_name {
     Some(workspace_name) => config
-      .inactive_workspace_configs(&state.workspaces())
+      .inactive_workspace_configs(&active_workspaces)
       .into_iter()
       .find(|config| config.name == workspace_name)
+      .cloned()
+      .or_else(|| {
+        dynamic_workspace_config(
+          workspace_name,
+          &active_workspaces,
+          config,
+        )
+      })
       .with_context(|| {
         format!(
           "Workspace with name '{}' doesn't exist or is already active.",
@@ -103,14 +114,53 @@ fn workspace_config(
       .and_then(|target_monitor| {
         config.workspace_config_for_monitor(
           &target_monitor,
-          &state.workspaces(),
+          &active_workspaces,
         )
       })
       .or_else(|| {
-        config.next_inactive_workspace_config(&state.workspaces())
+        config.next_inactive_workspace_config(&active_workspaces)
+      })
+      .cloned()
+      .or_else(|| {
+        // Use the lowest number that isn't already taken as the name.
+        (1..)
+          .map(|index: u32| index.to_string())
+          .find(|name| {
+            config.workspace_config_index(name).is_none()
+              && active_workspaces
+                .iter()
+                .all(|workspace| workspace.config().name != *name)
+          })
+          .and_then(|name| {
+            dynamic_workspace_config(&name, &active_workspaces, config)
+          })
       })
       .context("No workspace config available to activate workspace."),
-  };
+  }
+}
+
+/// Creates a workspace config for a workspace that isn't defined in the
+/// user config.
+///
+/// Returns `None` if `general.dynamic_workspaces` is disabled or if a
+/// workspace with the given name is already active.
+fn dynamic_workspace_config(
+  workspace_name: &str,
+  active_workspaces: &[Workspace],
+  config: &UserConfig,
+) -> Option<WorkspaceConfig> {
+  let is_active = active_workspaces
+    .iter()
+    .any(|workspace| workspace.config().name == workspace_name);
 
-  found_config.cloned()
+  match config.value.general.dynamic_workspaces && !is_active {
+    true => Some(WorkspaceConfig {
+      name: workspace_name.to_string(),
+      display_name: None,
+      bind_to_monitor: None,
+      keep_alive: false,
+      outer_gap: None,
+    }),
+    false => None,
+  }
 }
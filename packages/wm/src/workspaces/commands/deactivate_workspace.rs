@@ -9,9 +9,9 @@ use crate::{
 /// parent monitor and emits a `WorkspaceDeactivated` event.
 pub fn deactivate_workspace(
   workspace: Workspace,
-  state: &WmState,
+  state: &mut WmState,
 ) -> anyhow::Result<()> {
-  detach_container(workspace.clone().into())?;
+  detach_container(workspace.clone().into(), state)?;
 
   state.emit_event(WmEvent::WorkspaceDeactivated {
     deactivated_id: workspace.id(),
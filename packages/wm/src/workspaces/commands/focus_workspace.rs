@@ -48,11 +48,26 @@ pub fn focus_workspace(
 
     // Get the currently displayed workspace on the same monitor that the
     // workspace to focus is on.
-    let displayed_workspace = target_workspace
-      .monitor()
-      .and_then(|monitor| monitor.displayed_workspace())
+    let monitor = target_workspace.monitor().context("No monitor.")?;
+    let displayed_workspace = monitor
+      .displayed_workspace()
       .context("No workspace is currently displayed.")?;
 
+    // Add the workspace that's being replaced to the monitor's history.
+    if displayed_workspace.id() != target_workspace.id() {
+      let displayed_name = displayed_workspace.config().name;
+      let history =
+        state.workspace_history.entry(monitor.id()).or_default();
+
+      history.retain(|name| *name != displayed_name);
+      history.push(displayed_name);
+
+      let max_depth = config.value.general.workspace_history_depth;
+      if history.len() > max_depth {
+        history.drain(..history.len() - max_depth);
+      }
+    }
+
     // Set focus to whichever window last had focus in workspace. If the
     // workspace has no windows, then set focus to the workspace itself.
     let container_to_focus = target_workspace
@@ -0,0 +1,21 @@
+use tracing::info;
+
+use crate::{wm_event::WmEvent, wm_state::WmState, workspaces::Workspace};
+
+/// Locks the layout of the workspace, so that commands which would add,
+/// remove, or move tiling windows are skipped. Windows that get managed
+/// on the workspace are floated instead.
+pub fn lock_workspace(
+  workspace: Workspace,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  info!("Locking workspace: '{}'.", workspace.config().name);
+
+  workspace.set_is_locked(true);
+
+  state.emit_event(WmEvent::WorkspaceUpdated {
+    updated_workspace: workspace.to_dto()?,
+  });
+
+  Ok(())
+}
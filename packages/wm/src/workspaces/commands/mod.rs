@@ -1,11 +1,21 @@
 mod activate_workspace;
 mod deactivate_workspace;
 mod focus_workspace;
+mod lock_workspace;
 mod move_workspace_in_direction;
+mod rename_workspace;
 mod sort_workspaces;
+mod switch_to_previous_workspace;
+mod toggle_monocle;
+mod unlock_workspace;
 
 pub use activate_workspace::*;
 pub use deactivate_workspace::*;
 pub use focus_workspace::*;
+pub use lock_workspace::*;
 pub use move_workspace_in_direction::*;
+pub use rename_workspace::*;
 pub use sort_workspaces::*;
+pub use switch_to_previous_workspace::*;
+pub use toggle_monocle::*;
+pub use unlock_workspace::*;
@@ -0,0 +1,59 @@
+use anyhow::bail;
+use tracing::info;
+
+use crate::{
+  user_config::UserConfig, wm_event::WmEvent, wm_state::WmState,
+  workspaces::Workspace,
+};
+
+/// Changes the display name of a workspace and emits a
+/// `WorkspaceUpdated` event.
+///
+/// The workspace's name in the user config is kept as-is, since it's used
+/// to identify the workspace. The display name is therefore reset to the
+/// configured value when the config is reloaded.
+pub fn rename_workspace(
+  workspace: Workspace,
+  display_name: &str,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let active_configs = state
+    .workspaces()
+    .into_iter()
+    .map(|workspace| workspace.config())
+    .collect::<Vec<_>>();
+
+  // Check against both active workspaces (which might have been renamed)
+  // and the workspaces defined in the user config.
+  let is_name_taken = config
+    .value
+    .workspaces
+    .iter()
+    .chain(active_configs.iter())
+    .any(|other| {
+      other.name != workspace.config().name
+        && (other.name == display_name
+          || other.display_name.as_deref() == Some(display_name))
+    });
+
+  if is_name_taken {
+    bail!("Workspace with name '{}' already exists.", display_name);
+  }
+
+  info!(
+    "Renaming workspace '{}' to '{}'.",
+    workspace.config().name,
+    display_name
+  );
+
+  let mut workspace_config = workspace.config();
+  workspace_config.display_name = Some(display_name.to_string());
+  workspace.set_config(workspace_config);
+
+  state.emit_event(WmEvent::WorkspaceUpdated {
+    updated_workspace: workspace.to_dto()?,
+  });
+
+  Ok(())
+}
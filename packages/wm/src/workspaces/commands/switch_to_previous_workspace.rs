@@ -0,0 +1,60 @@
+use anyhow::Context;
+
+use super::focus_workspace;
+use crate::{
+  containers::traits::CommonGetters, user_config::UserConfig,
+  wm_state::WmState, workspaces::WorkspaceTarget,
+};
+
+/// Switches the focused monitor to the workspace it most recently
+/// displayed.
+///
+/// Unlike `focus --recent-workspace`, repeated calls keep going further
+/// back in the monitor's workspace history.
+pub fn switch_to_previous_workspace(
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let monitor = state
+    .focused_container()
+    .and_then(|focused| focused.monitor())
+    .context("No monitor is currently focused.")?;
+
+  let displayed_workspace = monitor
+    .displayed_workspace()
+    .context("No workspace is currently displayed.")?;
+
+  let original_history = state
+    .workspace_history
+    .get(&monitor.id())
+    .cloned()
+    .unwrap_or_default();
+
+  let mut history = original_history.clone();
+
+  // The displayed workspace might be in the history from being displayed
+  // earlier on.
+  history.retain(|name| *name != displayed_workspace.config().name);
+
+  if let Some(workspace_name) = history.pop() {
+    let res = focus_workspace(
+      WorkspaceTarget::Name(workspace_name),
+      state,
+      config,
+    );
+
+    // Keep the original history if the workspace couldn't be focused.
+    if let Err(err) = res {
+      state
+        .workspace_history
+        .insert(monitor.id(), original_history);
+      return Err(err);
+    }
+  }
+
+  // Overwrite the history entry added by `focus_workspace`, so that the
+  // workspaces that were switched away from aren't revisited.
+  state.workspace_history.insert(monitor.id(), history);
+
+  Ok(())
+}
@@ -0,0 +1,26 @@
+use crate::{wm_event::WmEvent, wm_state::WmState, workspaces::Workspace};
+
+/// Toggles monocle mode for the workspace, where each tiling window fills
+/// the whole workspace. Only the focused window is on top, so focus can
+/// be moved between windows (e.g. via `focus --direction`) to cycle
+/// through them.
+pub fn toggle_monocle(
+  workspace: Workspace,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  workspace.set_is_monocle(!workspace.is_monocle());
+
+  state
+    .pending_sync
+    .containers_to_redraw
+    .push(workspace.clone().into());
+
+  // Reassign focus so that the focused window is brought to the front.
+  state.pending_sync.focus_change = true;
+
+  state.emit_event(WmEvent::WorkspaceUpdated {
+    updated_workspace: workspace.to_dto()?,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,19 @@
+use tracing::info;
+
+use crate::{wm_event::WmEvent, wm_state::WmState, workspaces::Workspace};
+
+/// Unlocks the layout of the workspace after `lock_workspace`.
+pub fn unlock_workspace(
+  workspace: Workspace,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  info!("Unlocking workspace: '{}'.", workspace.config().name);
+
+  workspace.set_is_locked(false);
+
+  state.emit_event(WmEvent::WorkspaceUpdated {
+    updated_workspace: workspace.to_dto()?,
+  });
+
+  Ok(())
+}
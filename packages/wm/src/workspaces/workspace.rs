@@ -32,6 +32,8 @@ struct WorkspaceInner {
   tiling_direction: TilingDirection,
   config: WorkspaceConfig,
   outer_gap: RectDelta,
+  is_monocle: bool,
+  is_locked: bool,
 }
 
 /// User-friendly representation of a workspace.
@@ -53,6 +55,8 @@ pub struct WorkspaceDto {
   x: i32,
   y: i32,
   tiling_direction: TilingDirection,
+  is_monocle: bool,
+  is_locked: bool,
 }
 
 impl Workspace {
@@ -69,6 +73,8 @@ impl Workspace {
       tiling_direction,
       config,
       outer_gap,
+      is_monocle: false,
+      is_locked: false,
     };
 
     Self(Rc::new(RefCell::new(workspace)))
@@ -97,6 +103,27 @@ impl Workspace {
     self.0.borrow_mut().outer_gap = outer_gap;
   }
 
+  /// Whether tiling windows in the workspace are stacked on top of each
+  /// other, with each filling the whole workspace.
+  pub fn is_monocle(&self) -> bool {
+    self.0.borrow().is_monocle
+  }
+
+  pub fn set_is_monocle(&self, is_monocle: bool) {
+    self.0.borrow_mut().is_monocle = is_monocle;
+  }
+
+  /// Whether the layout of the workspace is locked. Windows can't be
+  /// added to, removed from, or moved within the tiling layout while
+  /// locked, and new windows are floated instead.
+  pub fn is_locked(&self) -> bool {
+    self.0.borrow().is_locked
+  }
+
+  pub fn set_is_locked(&self, is_locked: bool) {
+    self.0.borrow_mut().is_locked = is_locked;
+  }
+
   pub fn to_dto(&self) -> anyhow::Result<ContainerDto> {
     let rect = self.to_rect()?;
     let config = self.config();
@@ -121,6 +148,8 @@ impl Workspace {
       x: rect.x(),
       y: rect.y(),
       tiling_direction: self.tiling_direction(),
+      is_monocle: self.is_monocle(),
+      is_locked: self.is_locked(),
     }))
   }
 }